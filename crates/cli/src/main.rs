@@ -27,7 +27,13 @@ fn main() {
 
     let cli = Cli::parse();
 
-    let config = FluffConfig::from_root(None, false, None).unwrap();
+    let config = match FluffConfig::from_root(None, false, None) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    };
     match cli.command {
         Commands::Lint(LintArgs { paths, format }) => {
             let mut linter = linter(config, format);
@@ -3,10 +3,12 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use pprof::criterion::{Output, PProfProfiler};
 use sqruff_lib::core::config::FluffConfig;
 use sqruff_lib::core::dialects::base::Dialect;
+use sqruff_lib::core::dialects::init::dialect_selector;
 use sqruff_lib::core::parser::context::ParseContext;
 use sqruff_lib::core::parser::matchable::Matchable;
 use sqruff_lib::core::parser::segments::base::ErasedSegment;
 use sqruff_lib::core::parser::segments::test_functions::{fresh_ansi_dialect, lex};
+use sqruff_lib::dialects::ansi::ansi_dialect;
 use sqruff_lib::dialects::SyntaxKind;
 
 #[cfg(all(
@@ -105,6 +107,18 @@ fn parse(c: &mut Criterion) {
     }
 }
 
+/// Selecting a built in dialect clones the cached dialect, where building it
+/// from scratch constructs and expands every grammar.
+fn dialect(c: &mut Criterion) {
+    c.bench_function("dialect_build", |b| {
+        b.iter(|| black_box(ansi_dialect()));
+    });
+
+    c.bench_function("dialect_selector", |b| {
+        b.iter(|| black_box(dialect_selector("ansi").unwrap()));
+    });
+}
+
 fn mk_segments<'a>(
     dialect: &'a Dialect,
     config: &FluffConfig,
@@ -125,10 +139,10 @@ fn mk_segments<'a>(
 criterion_group! {
     name = benches;
     config = Criterion::default().with_profiler(PProfProfiler::new(100, Output::Flamegraph(None)));
-    targets = parse
+    targets = parse, dialect
 }
 
 #[cfg(not(unix))]
-criterion_group!(benches, parse);
+criterion_group!(benches, parse, dialect);
 
 criterion_main!(benches);
@@ -1,13 +1,17 @@
 use std::mem::take;
+use std::ops::Range;
 
 use ahash::AHashMap;
+use itertools::Itertools;
 
 use crate::cli::formatters::OutputStreamFormatter;
-use crate::core::config::FluffConfig;
-use crate::core::dialects::init::dialect_selector;
+use crate::core::config::{FluffConfig, Value};
+use crate::core::dialects::init::check_dialect_name;
 use crate::core::errors::{SQLBaseError, SQLFluffUserError};
 use crate::core::linter::linter::Linter;
-use crate::core::rules::base::ErasedRule;
+use crate::core::parser::lexer::{Lexer, StringOrTemplate};
+use crate::core::rules::base::{ErasedRule, RuleGroups};
+use crate::dialects::{SyntaxKind, SyntaxSet};
 
 pub fn get_simple_config(
     dialect: Option<String>,
@@ -17,13 +21,7 @@ pub fn get_simple_config(
 ) -> Result<FluffConfig, SQLFluffUserError> {
     let mut overrides = AHashMap::new();
     if let Some(dialect) = dialect {
-        let selected = dialect_selector(&dialect);
-        if selected.is_none() {
-            return Err(SQLFluffUserError::new(format!(
-                "Error loading dialect '{}': {}",
-                dialect, "Dialect not found"
-            )));
-        }
+        check_dialect_name(&dialect)?;
         overrides.insert("dialect".to_owned(), dialect);
     }
     if let Some(rules) = rules {
@@ -72,3 +70,145 @@ pub fn fix(sql: &str, rules: Vec<ErasedRule>) -> String {
     let mut result = linter.lint_string_wrapped(sql, None, Some(true), rules);
     take(&mut result.paths[0].files[0]).fix_string()
 }
+
+/// Split a SQL string into tokens without parsing it.
+///
+/// Each token is returned as its kind, its raw text and its byte range in
+/// `sql`. Whitespace and comments are included, so the ranges cover the whole
+/// input, which suits syntax highlighting.
+pub fn tokenize<'a>(
+    sql: &'a str,
+    config: &FluffConfig,
+) -> Vec<(SyntaxKind, &'a str, Range<usize>)> {
+    let lexer = Lexer::new(config, None);
+    // Lexing only fails for templated input, never for a plain string.
+    let (segments, _errors) = lexer.lex(StringOrTemplate::String(sql)).unwrap();
+
+    segments
+        .into_iter()
+        .filter_map(|segment| {
+            let span = segment.get_position_marker()?.source_slice.clone();
+            (!span.is_empty()).then(|| (segment.get_type(), &sql[span.clone()], span))
+        })
+        .collect()
+}
+
+/// Clauses which [`format_string`] starts on a line of their own.
+const CLAUSE_TYPES: SyntaxSet = SyntaxSet::new(&[
+    SyntaxKind::FromClause,
+    SyntaxKind::WhereClause,
+    SyntaxKind::GroupbyClause,
+    SyntaxKind::HavingClause,
+    SyntaxKind::QualifyClause,
+    SyntaxKind::OrderbyClause,
+    SyntaxKind::LimitClause,
+]);
+
+/// Format a SQL string.
+///
+/// Every layout rule not excluded by `config` is applied, so whitespace, comma
+/// placement and line breaks are normalised while everything else is left as
+/// written. Major clauses are moved onto their own line and indentation
+/// follows the indent and dedent markers of the parse tree.
+pub fn format_string(sql: &str, config: &FluffConfig) -> String {
+    let mut config = config.clone();
+    config.raw.get_mut("core").and_then(Value::as_map_mut).unwrap().insert(
+        "rule_allowlist".into(),
+        Value::Array(vec![Value::String(RuleGroups::Layout.as_ref().into())]),
+    );
+
+    let mut linter = Linter::new(config, None, None);
+    let rules = linter.get_rulepack().rules();
+
+    let mut result = linter.lint_string_wrapped(sql, None, Some(true), rules.clone());
+    let sql = break_before_clauses(&linter, &take(&mut result.paths[0].files[0]).fix_string());
+
+    // Reindent the lines introduced above.
+    let mut result = linter.lint_string_wrapped(&sql, None, Some(true), rules);
+    take(&mut result.paths[0].files[0]).fix_string()
+}
+
+/// Insert a newline before each of the [`CLAUSE_TYPES`] which doesn't already
+/// start a line.
+fn break_before_clauses(linter: &Linter, sql: &str) -> String {
+    let Some(tree) = linter.parse_string(sql, None, None, None).ok().and_then(|it| it.tree) else {
+        return sql.to_owned();
+    };
+
+    let mut breaks = tree
+        .recursive_crawl(CLAUSE_TYPES, true, None, true)
+        .into_iter()
+        .filter_map(|clause| clause.get_position_marker())
+        .map(|marker| marker.source_slice.start)
+        .filter_map(|start| {
+            let line = sql[..start].trim_end_matches([' ', '\t']);
+            (!line.is_empty() && !line.ends_with('\n')).then_some(line.len()..start)
+        })
+        .collect_vec();
+    breaks.sort_by_key(|range| range.start);
+
+    let mut formatted = sql.to_owned();
+    for range in breaks.into_iter().rev() {
+        formatted.replace_range(range, "\n");
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_string, get_simple_config, tokenize};
+    use crate::dialects::SyntaxKind;
+
+    #[test]
+    fn test_format_string_reflows_select() {
+        let config = get_simple_config(Some("ansi".into()), None, None, None).unwrap();
+
+        let formatted = format_string(
+            "select a ,b,   c+1 as d from tbl  where a=1 group by a,b order by a\n",
+            &config,
+        );
+
+        assert_eq!(
+            formatted,
+            "select\n    a,\n    b,\n    c + 1 as d\nfrom tbl\nwhere a = 1\ngroup by a, b\norder \
+             by a\n"
+        );
+        assert_eq!(format_string(&formatted, &config), formatted);
+    }
+
+    #[test]
+    fn test_format_string_leaves_non_layout_issues() {
+        let config = get_simple_config(Some("ansi".into()), None, None, None).unwrap();
+
+        // Capitalisation is not a layout concern, so mixed case keywords survive.
+        assert_eq!(format_string("SELECT a from tbl\n", &config), "SELECT a\nfrom tbl\n");
+    }
+
+    #[test]
+    fn test_format_string_reindents_subquery() {
+        let config = get_simple_config(Some("ansi".into()), None, None, None).unwrap();
+
+        assert_eq!(
+            format_string("select a from (select b from c where b > 1) as d\n", &config),
+            "select a\nfrom (\n    select b\n    from c\n    where b > 1\n) as d\n"
+        );
+    }
+
+    #[test]
+    fn test_tokenize_comment() {
+        let config = get_simple_config(Some("ansi".into()), None, None, None).unwrap();
+
+        let tokens = tokenize("SELECT a -- c", &config);
+
+        assert_eq!(
+            tokens,
+            [
+                (SyntaxKind::Word, "SELECT", 0..6),
+                (SyntaxKind::Whitespace, " ", 6..7),
+                (SyntaxKind::Word, "a", 7..8),
+                (SyntaxKind::Whitespace, " ", 8..9),
+                (SyntaxKind::InlineComment, "-- c", 9..13),
+            ]
+        );
+    }
+}
@@ -8,7 +8,9 @@ use itertools::Itertools;
 use serde::Deserialize;
 
 use super::dialects::base::Dialect;
-use crate::core::dialects::init::{dialect_readout, dialect_selector, get_default_dialect};
+use crate::core::dialects::init::{
+    dialect_readout, dialect_selector, get_default_dialect, DialectKind,
+};
 use crate::core::errors::SQLFluffUserError;
 use crate::utils::reflow::config::ReflowConfig;
 
@@ -40,7 +42,7 @@ pub struct FluffConfig {
 
 impl Default for FluffConfig {
     fn default() -> Self {
-        Self::new(<_>::default(), None, None)
+        Self::new(<_>::default(), None, None).unwrap()
     }
 }
 
@@ -57,7 +59,7 @@ impl FluffConfig {
         self.reflow = ReflowConfig::from_fluff_config(self);
     }
 
-    pub fn from_source(source: &str) -> FluffConfig {
+    pub fn from_source(source: &str) -> Result<FluffConfig, SQLFluffUserError> {
         let configs = ConfigLoader {}.from_source(source);
 
         FluffConfig::new(configs, None, None)
@@ -72,7 +74,7 @@ impl FluffConfig {
         configs: AHashMap<String, Value>,
         extra_config_path: Option<String>,
         indentation: Option<FluffConfigIndentation>,
-    ) -> Self {
+    ) -> Result<Self, SQLFluffUserError> {
         fn nested_combine(
             mut a: AHashMap<String, Value>,
             b: AHashMap<String, Value>,
@@ -106,7 +108,10 @@ impl FluffConfig {
             _value => get_default_dialect(),
         };
 
-        let dialect = dialect_selector(dialect).unwrap();
+        let dialect = match dialect_selector(dialect) {
+            Some(dialect) => dialect,
+            None => return Err(DialectKind::from_name(dialect).unwrap_err()),
+        };
 
         for (in_key, out_key) in [
             // Deal with potential ignore & warning parameters
@@ -142,7 +147,7 @@ impl FluffConfig {
             reflow: ReflowConfig::default(),
         };
         this.reflow = ReflowConfig::from_fluff_config(&this);
-        this
+        Ok(this)
     }
 
     pub fn with_sql_file_exts(mut self, exts: Vec<String>) -> Self {
@@ -172,7 +177,7 @@ impl FluffConfig {
             }
         }
 
-        Ok(FluffConfig::new(config, extra_config_path, None))
+        FluffConfig::new(config, extra_config_path, None)
     }
 
     pub fn from_kwargs(
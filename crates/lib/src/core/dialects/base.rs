@@ -6,14 +6,23 @@ use std::sync::Arc;
 use ahash::{AHashMap, AHashSet};
 
 use crate::core::dialects::init::DialectKind;
-use crate::core::parser::lexer::Matcher;
+use crate::core::parser::lexer::{Matcher, Pattern};
 use crate::core::parser::matchable::Matchable;
 use crate::core::parser::parsers::StringParser;
+use crate::core::parser::segments::base::{
+    CommentSegment, CommentSegmentNewArgs, NewlineSegment, NewlineSegmentNewArgs,
+    WhitespaceSegment, WhitespaceSegmentNewArgs,
+};
 use crate::core::parser::segments::keyword::KeywordSegment;
 use crate::core::parser::types::DialectElementType;
 use crate::dialects::ansi::NodeMatcher;
+use crate::dialects::SyntaxKind;
 use crate::helpers::{capitalize, ToErasedSegment};
 
+/// The openers of inline comments, recorded as the `trim_start` of the
+/// comments lexed by matchers from [`Dialect::insert_comment_matcher`].
+const INLINE_COMMENT_PREFIXES: &[&str] = &["--", "#", "//"];
+
 #[derive(Debug, Clone, Default)]
 pub struct Dialect {
     pub(crate) name: DialectKind,
@@ -23,6 +32,8 @@ pub struct Dialect {
     library: AHashMap<Cow<'static, str>, DialectElementType>,
     sets: AHashMap<&'static str, AHashSet<&'static str>>,
     bracket_collections: AHashMap<&'static str, AHashSet<BracketPair>>,
+    /// Whether [`Dialect::expand`] has run since the dialect was last changed.
+    expanded: bool,
 }
 
 impl PartialEq for Dialect {
@@ -44,6 +55,7 @@ impl Dialect {
         &mut self,
         iter: impl IntoIterator<Item = (Cow<'static, str>, DialectElementType)> + Clone,
     ) {
+        self.expanded = false;
         self.library.extend(iter);
     }
 
@@ -57,6 +69,7 @@ impl Dialect {
     }
 
     pub fn replace_grammar(&mut self, name: &str, match_grammar: Arc<dyn Matchable>) {
+        self.expanded = false;
         match self.library.get_mut(name).unwrap() {
             DialectElementType::Matchable(matchable) => {
                 Arc::get_mut(matchable)
@@ -106,6 +119,58 @@ impl Dialect {
         self.lexer_matchers = Some(buff);
     }
 
+    /// Lex text matching `pattern` as a comment, trying it before the matcher
+    /// named `before`.
+    ///
+    /// `kind` is either [`SyntaxKind::InlineComment`] or
+    /// [`SyntaxKind::BlockComment`]. Block comments may span several lines.
+    pub fn insert_comment_matcher(
+        &mut self,
+        name: &'static str,
+        pattern: &'static str,
+        kind: SyntaxKind,
+        before: &str,
+    ) {
+        let matcher = match kind {
+            SyntaxKind::InlineComment => Matcher::regex(name, pattern, |slice, marker| {
+                let trim_start = INLINE_COMMENT_PREFIXES
+                    .iter()
+                    .copied()
+                    .filter(|prefix| slice.starts_with(prefix))
+                    .collect();
+
+                CommentSegment::create(
+                    slice,
+                    marker.into(),
+                    CommentSegmentNewArgs {
+                        r#type: SyntaxKind::InlineComment,
+                        trim_start: Some(trim_start),
+                    },
+                )
+            }),
+            SyntaxKind::BlockComment => Matcher::regex(name, pattern, |slice, marker| {
+                CommentSegment::create(
+                    slice,
+                    marker.into(),
+                    CommentSegmentNewArgs { r#type: SyntaxKind::BlockComment, trim_start: None },
+                )
+            })
+            .subdivider(Pattern::regex("newline", r"\r\n|\n", |slice, marker| {
+                NewlineSegment::create(slice, marker.into(), NewlineSegmentNewArgs {})
+            }))
+            .post_subdivide(Pattern::regex(
+                "whitespace",
+                r"[^\S\r\n]+",
+                |slice, marker| {
+                    WhitespaceSegment::create(slice, marker.into(), WhitespaceSegmentNewArgs {})
+                },
+            )),
+            _ => panic!("{kind:?} is not a kind of comment"),
+        };
+
+        self.insert_lexer_matchers(vec![matcher], before);
+    }
+
     pub fn patch_lexer_matchers(&mut self, lexer_patch: Vec<Matcher>) {
         let mut buff = Vec::with_capacity(self.lexer_matchers.as_ref().map_or(0, Vec::len));
         if self.lexer_matchers.is_none() {
@@ -148,6 +213,7 @@ impl Dialect {
             label
         );
 
+        self.expanded = false;
         match self.sets.entry(label) {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => entry.insert(<_>::default()),
@@ -178,6 +244,7 @@ impl Dialect {
             "Invalid bracket set. Consider using another identifier instead."
         );
 
+        self.expanded = false;
         self.bracket_collections.entry(label).or_default()
     }
 
@@ -188,6 +255,13 @@ impl Dialect {
         }
     }
 
+    /// Returns the bracket pairs configured in a bracket set, ordered by name.
+    pub fn bracket_pairs(&self, set_name: &str) -> Vec<BracketPair> {
+        let mut pairs = Vec::from_iter(self.bracket_sets(set_name));
+        pairs.sort_unstable();
+        pairs
+    }
+
     pub fn r#ref(&self, name: &str) -> Arc<dyn Matchable> {
         match self.library.get(name) {
             Some(DialectElementType::Matchable(matchable)) => matchable.clone(),
@@ -214,7 +288,16 @@ impl Dialect {
         }
     }
 
+    /// Resolve segment generators and create the keyword segments of the
+    /// keyword sets.
+    ///
+    /// Expanding again is a no-op unless the dialect was changed in between,
+    /// as happens when one dialect is derived from another expanded one.
     pub fn expand(&mut self) {
+        if self.expanded {
+            return;
+        }
+
         // Temporarily take ownership of 'library' from 'self' to avoid borrow checker
         // errors during mutation.
         let mut library = std::mem::take(&mut self.library);
@@ -225,6 +308,18 @@ impl Dialect {
         }
         self.library = library;
 
+        for (set_name, pairs) in &self.bracket_collections {
+            for (bracket_type, start, end, _) in pairs {
+                for segment_name in [start, end] {
+                    assert!(
+                        self.library.contains_key(*segment_name),
+                        "Bracket pair '{bracket_type}' in '{set_name}' refers to '{segment_name}' \
+                         which was not found in the dialect."
+                    );
+                }
+            }
+        }
+
         for keyword_set in ["unreserved_keywords", "reserved_keywords"] {
             if let Some(keywords) = self.sets.get(keyword_set) {
                 for kw in keywords {
@@ -250,6 +345,8 @@ impl Dialect {
                 }
             }
         }
+
+        self.expanded = true;
     }
 
     pub fn root_segment_name(&self) -> &'static str {
@@ -262,3 +359,35 @@ impl Dialect {
 }
 
 pub type BracketPair = (&'static str, &'static str, &'static str, bool);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dialects::ansi;
+
+    #[test]
+    fn test_dialect_expand_twice_is_stable() {
+        let mut dialect = ansi::raw_dialect();
+        dialect.expand();
+
+        let len = dialect.library.len();
+        let select = dialect.grammar("SelectKeywordSegment");
+
+        dialect.expand();
+
+        assert_eq!(dialect.library.len(), len);
+        assert!(Arc::ptr_eq(&select, &dialect.grammar("SelectKeywordSegment")));
+    }
+
+    #[test]
+    fn test_dialect_expand_after_change() {
+        let mut dialect = ansi::raw_dialect();
+        dialect.expand();
+
+        dialect.sets_mut("unreserved_keywords").insert("SQRUFF");
+        dialect.expand();
+
+        assert!(dialect.library.contains_key("SqruffKeywordSegment"));
+    }
+}
@@ -28,6 +28,7 @@ pub struct SQLBaseError {
     pub line_pos: usize,
     pub description: String,
     pub rule: Option<ErasedRule>,
+    pub position_marker: Option<PositionMarker>,
 }
 
 impl Default for SQLBaseError {
@@ -46,6 +47,7 @@ impl SQLBaseError {
             line_no: 0,
             line_pos: 0,
             rule: None,
+            position_marker: None,
         }
     }
 
@@ -58,6 +60,7 @@ impl SQLBaseError {
 
         self.line_no = line_no;
         self.line_pos = line_pos;
+        self.position_marker = Some(position_marker);
     }
 
     pub fn desc(&self) -> &str {
@@ -143,7 +146,6 @@ impl SqlError for SQLTemplaterError {
 /// An error which should be fed back to the user.
 #[derive(Debug)]
 pub struct SQLFluffUserError {
-    #[allow(dead_code)]
     value: String,
 }
 
@@ -153,6 +155,12 @@ impl SQLFluffUserError {
     }
 }
 
+impl std::fmt::Display for SQLFluffUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
 // Not from SQLFluff but translates Python value error
 #[derive(Debug)]
 pub struct ValueError {
@@ -194,18 +202,14 @@ impl SQLParseError {
 
 impl From<SQLParseError> for SQLBaseError {
     fn from(value: SQLParseError) -> Self {
-        let (mut line_no, mut line_pos) = Default::default();
-
         let pos_marker = value.segment.and_then(|segment| segment.get_position_marker());
 
-        if let Some(pos_marker) = pos_marker {
-            (line_no, line_pos) = pos_marker.source_position();
-        }
-
         Self::new().config(|this| {
             this.fatal = true;
-            this.line_no = line_no;
-            this.line_pos = line_pos;
+            this.description = value.description;
+            if let Some(pos_marker) = pos_marker {
+                this.set_position_marker(pos_marker);
+            }
         })
     }
 }
@@ -26,3 +26,15 @@ pub struct ParsedString {
     pub f_name: String,
     pub source_str: String,
 }
+
+impl ParsedString {
+    /// Return the original source text spanned by `segment`.
+    ///
+    /// The slice is taken from the source file using the segment's position
+    /// marker, so formatting is preserved exactly as written rather than
+    /// being reconstructed from the raw segments.
+    pub fn source_str_of(&self, segment: &ErasedSegment) -> Option<&str> {
+        let marker = segment.get_position_marker()?;
+        self.templated_file.source_str.get(marker.source_slice)
+    }
+}
@@ -182,6 +182,7 @@ mod test {
 
     /// Test _build_up_fixed_source_string. This is part of fix_string().
     #[test]
+    #[allow(clippy::single_range_in_vec_init)]
     fn test_linted_file_build_up_fixed_source_string() {
         let tests = [
             // Trivial example
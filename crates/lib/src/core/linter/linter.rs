@@ -1,5 +1,6 @@
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::mem::take;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -14,20 +15,32 @@ use super::linted_dir::LintedDir;
 use super::runner::RunnerContext;
 use crate::cli::formatters::OutputStreamFormatter;
 use crate::core::config::FluffConfig;
-use crate::core::errors::{SQLFluffUserError, SQLLexError, SQLLintError, SQLParseError, SqlError};
+use crate::core::errors::{
+    SQLBaseError, SQLFluffUserError, SQLLexError, SQLLintError, SQLParseError, SqlError,
+};
 use crate::core::linter::common::{ParsedString, RenderedFile};
 use crate::core::linter::linted_file::LintedFile;
 use crate::core::linter::linting_result::LintingResult;
 use crate::core::parser::lexer::{Lexer, StringOrTemplate};
+use crate::core::parser::markers::PositionMarker;
 use crate::core::parser::parser::Parser;
 use crate::core::parser::segments::base::ErasedSegment;
 use crate::core::parser::segments::bracketed::BracketedSegment;
 use crate::core::parser::segments::fix::{AnchorEditInfo, SourceFix};
 use crate::core::rules::base::{ErasedRule, LintFix, LintPhase, RulePack};
 use crate::core::templaters::base::{RawTemplater, TemplatedFile, Templater};
+use crate::dialects::{SyntaxKind, SyntaxSet};
 use crate::helpers::ToErasedSegment;
 use crate::rules::get_ruleset;
 
+/// A rule violation found by [`Linter::check_string`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintViolation {
+    pub rule_code: &'static str,
+    pub message: String,
+    pub position: PositionMarker,
+}
+
 pub struct Linter {
     config: FluffConfig,
     pub formatter: Option<OutputStreamFormatter>,
@@ -130,6 +143,51 @@ impl Linter {
         self.lint_parsed(parsed, rules, fix)
     }
 
+    /// Lint a string with the configured rules and return the violations
+    /// found, in source order.
+    ///
+    /// Only rule violations are returned; parsing and templating errors are
+    /// available through [`Linter::lint_string`].
+    pub fn check_string(&self, sql: &str) -> Vec<LintViolation> {
+        let rules = self.get_rulepack().rules();
+        let linted_file = self.lint_string(sql, None, None, None, rules, false);
+
+        Self::lint_violations(linted_file.violations)
+    }
+
+    /// Lint a string with the configured rules and apply their fixes.
+    ///
+    /// This is a thin wrapper around [`Linter::lint_string`] with `fix` set and
+    /// adds no conflict handling of its own. Overlapping fixes are handled by
+    /// the fix loop in `lint_parsed`: rules are applied one at a time, each
+    /// seeing the fixes of the rules before it, the pass is repeated at most
+    /// `loop_limit` (10) times, and fixes which would bring back an earlier
+    /// version of the SQL are skipped.
+    ///
+    /// Returns the fixed SQL along with the violations found in the original
+    /// string, in source order.
+    pub fn fix_string(&self, sql: &str) -> (String, Vec<LintViolation>) {
+        let rules = self.get_rulepack().rules();
+        let mut linted_file = self.lint_string(sql, None, None, None, rules, true);
+        let violations = Self::lint_violations(take(&mut linted_file.violations));
+
+        (linted_file.fix_string(), violations)
+    }
+
+    fn lint_violations(violations: Vec<SQLBaseError>) -> Vec<LintViolation> {
+        violations
+            .into_iter()
+            .filter_map(|violation| {
+                Some(LintViolation {
+                    rule_code: violation.rule.as_ref()?.code(),
+                    position: violation.position_marker?,
+                    message: violation.description,
+                })
+            })
+            .sorted_by_key(|violation| violation.position.source_slice.start)
+            .collect()
+    }
+
     pub fn lint_paths(&mut self, mut paths: Vec<PathBuf>, fix: bool) -> LintingResult {
         let mut result = LintingResult::new();
 
@@ -424,9 +482,37 @@ impl Linter {
             }
         };
 
+        if config.raw["core"]["recover_from_parse_errors"].to_bool() {
+            if let Some(tree) = &parsed {
+                violations.extend(
+                    tree.recursive_crawl(
+                        const { SyntaxSet::single(SyntaxKind::Unparsable) },
+                        false,
+                        None,
+                        true,
+                    )
+                    .into_iter()
+                    .map(Self::unparsable_error),
+                );
+            }
+        }
+
         (parsed, violations)
     }
 
+    fn unparsable_error(segment: ErasedSegment) -> SQLParseError {
+        let raw = segment.raw();
+        let mut snippet: String = raw.chars().take(40).collect();
+        if raw.chars().count() > 40 {
+            snippet.push_str("...");
+        }
+
+        SQLParseError {
+            description: format!("Found unparsable section: {snippet:?}"),
+            segment: Some(segment),
+        }
+    }
+
     /// Lex a templated file.
     ///
     /// NOTE: This potentially mutates the config, so make sure to
@@ -603,8 +689,16 @@ pub(crate) fn compute_anchor_edit_info(fixes: Vec<LintFix>) -> AHashMap<Uuid, An
 
 #[cfg(test)]
 mod tests {
-    use crate::core::config::FluffConfig;
+    use std::sync::Arc;
+
+    use crate::cli::formatters::OutputStreamFormatter;
+    use crate::core::config::{FluffConfig, Value};
+    use crate::core::errors::SQLFluffUserError;
     use crate::core::linter::linter::Linter;
+    use crate::core::templaters::base::{
+        RawFileSlice, TemplatedFile, TemplatedFileSlice, Templater,
+    };
+    use crate::dialects::{SyntaxKind, SyntaxSet};
 
     fn normalise_paths(paths: Vec<String>) -> Vec<String> {
         paths.into_iter().map(|path| path.replace(['/', '\\'], ".")).collect()
@@ -613,7 +707,7 @@ mod tests {
     #[test]
     fn test_linter_path_from_paths_dir() {
         // Test extracting paths from directories.
-        let lntr = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None); // Assuming Linter has a new() method for initialization
+        let lntr = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None); // Assuming Linter has a new() method for initialization
         let paths = lntr.paths_from_path("test/fixtures/lexer".into(), None, None, None, None);
         let expected = vec![
             "test.fixtures.lexer.basic.sql",
@@ -626,7 +720,7 @@ mod tests {
     #[test]
     fn test_linter_path_from_paths_default() {
         // Test .sql files are found by default.
-        let lntr = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None); // Assuming Linter has a new() method for initialization
+        let lntr = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None); // Assuming Linter has a new() method for initialization
         let paths = normalise_paths(lntr.paths_from_path(
             "test/fixtures/linter".into(),
             None,
@@ -643,8 +737,9 @@ mod tests {
     fn test_linter_path_from_paths_exts() {
         // Assuming Linter is initialized with a configuration similar to Python's
         // FluffConfig
-        let config =
-            FluffConfig::new(<_>::default(), None, None).with_sql_file_exts(vec![".txt".into()]);
+        let config = FluffConfig::new(<_>::default(), None, None)
+            .unwrap()
+            .with_sql_file_exts(vec![".txt".into()]);
         let lntr = Linter::new(config, None, None); // Assuming Linter has a new() method for initialization
 
         let paths = lntr.paths_from_path("test/fixtures/linter".into(), None, None, None, None);
@@ -662,7 +757,7 @@ mod tests {
 
     #[test]
     fn test_linter_path_from_paths_file() {
-        let lntr = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None); // Assuming Linter has a new() method for initialization
+        let lntr = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None); // Assuming Linter has a new() method for initialization
         let paths = lntr.paths_from_path(
             "test/fixtures/linter/indentation_errors.sql".into(),
             None,
@@ -691,14 +786,250 @@ mod tests {
     // test_lint_path_parallel_wrapper_exception
     // test__linter__get_runner_processes
     // test__linter__linting_unexpected_error_handled_gracefully
+    #[test]
+    fn test_linter_check_string() {
+        let config = FluffConfig::new(
+            [("core".into(), Value::Map([("rules".into(), Value::String("AM04".into()))].into()))]
+                .into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let linter = Linter::new(config, None, None);
+
+        let violations = linter.check_string("SELECT a\nFROM foo;\n\nSELECT *\nFROM foo;\n");
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule_code, "AM04");
+        assert_eq!(
+            violations[0].message,
+            "Outermost query should produce known number of columns."
+        );
+        assert_eq!(violations[0].position.source_position(), (4, 1));
+    }
+
+    #[test]
+    fn test_linter_fix_string() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("rules".into(), Value::String("CP01,LT01".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let linter = Linter::new(config, None, None);
+
+        let (fixed, violations) = linter.fix_string("SELECT a   \nfrom foo  \n");
+
+        assert_eq!(fixed, "SELECT a\nFROM foo\n");
+        assert_eq!(
+            violations.iter().map(|violation| violation.rule_code).collect::<Vec<_>>(),
+            ["LT01", "CP01", "LT01"]
+        );
+    }
+
+    #[test]
+    fn test_linter_fix_string_overlapping_fixes() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("rules".into(), Value::String("LT01,LT09".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let linter = Linter::new(config, None, None);
+
+        // LT09 breaks the select targets onto new lines, rewriting the same
+        // whitespace that LT01 fixes between them.
+        let (fixed, violations) = linter.fix_string("SELECT a,b   ,  c FROM foo\n");
+
+        assert_eq!(fixed, "SELECT\na,\nb,\nc\nFROM foo\n");
+        assert!(violations.iter().any(|violation| violation.rule_code == "LT01"));
+        assert!(violations.iter().any(|violation| violation.rule_code == "LT09"));
+
+        let (refixed, violations) = linter.fix_string(&fixed);
+        assert_eq!(refixed, fixed);
+        assert!(violations.is_empty());
+    }
+
+    /// Renders `{{ col }}` as `a + 1`, slicing the file the way a jinja
+    /// templater would.
+    struct ColTemplater;
+
+    impl Templater for ColTemplater {
+        fn name(&self) -> &str {
+            "col"
+        }
+
+        fn template_selection(&self) -> &str {
+            "templater"
+        }
+
+        fn config_pairs(&self) -> (String, String) {
+            ("templater".to_string(), self.name().to_string())
+        }
+
+        fn sequence_files(
+            &self,
+            f_names: Vec<String>,
+            _: Option<&FluffConfig>,
+            _: Option<&OutputStreamFormatter>,
+        ) -> Vec<String> {
+            f_names
+        }
+
+        fn process(
+            &self,
+            in_str: &str,
+            f_name: &str,
+            _config: Option<&FluffConfig>,
+            _formatter: Option<&OutputStreamFormatter>,
+        ) -> Result<TemplatedFile, SQLFluffUserError> {
+            const TAG: &str = "{{ col }}";
+            const RENDERED: &str = "a + 1";
+
+            let start = in_str.find(TAG).unwrap();
+            let end = start + TAG.len();
+            let rendered_end = start + RENDERED.len();
+            let templated_len = in_str.len() - TAG.len() + RENDERED.len();
+
+            let templated_str = in_str.replace(TAG, RENDERED);
+            let sliced_file = vec![
+                TemplatedFileSlice::new("literal", 0..start, 0..start),
+                TemplatedFileSlice::new("templated", start..end, start..rendered_end),
+                TemplatedFileSlice::new("literal", end..in_str.len(), rendered_end..templated_len),
+            ];
+            let raw_sliced = vec![
+                RawFileSlice::new(in_str[..start].into(), "literal".into(), 0, None, None),
+                RawFileSlice::new(TAG.into(), "templated".into(), start, None, None),
+                RawFileSlice::new(in_str[end..].into(), "literal".into(), end, None, None),
+            ];
+
+            Ok(TemplatedFile::new(
+                in_str.into(),
+                f_name.into(),
+                Some(templated_str),
+                Some(sliced_file),
+                Some(raw_sliced),
+            )
+            .unwrap())
+        }
+    }
+
+    #[test]
+    fn test_linter_templated_violation_positions() {
+        let config = FluffConfig::new(
+            [("core".into(), Value::Map([("rules".into(), Value::String("AL03".into()))].into()))]
+                .into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let linter = Linter::new(config, None, Some(Arc::new(ColTemplater)));
+
+        let violations = linter.check_string("SELECT {{ col }}, b + 1 FROM t\n");
+
+        assert_eq!(violations.len(), 2);
+        // A violation on rendered code points at the tag which produced it.
+        assert_eq!(violations[0].position.templated_slice, 7..12);
+        assert_eq!(violations[0].position.source_slice, 7..16);
+        // Literal code after the tag keeps its position in the source.
+        assert_eq!(violations[1].position.templated_slice, 14..19);
+        assert_eq!(violations[1].position.source_slice, 18..23);
+        assert_eq!(violations[1].position.source_position(), (1, 19));
+    }
+
+    #[test]
+    fn test_linter_recovers_from_parse_errors() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("recover_from_parse_errors".into(), Value::Bool(true))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let linter = Linter::new(config, None, None);
+
+        let parsed = linter
+            .parse_string(
+                "SELECT a FROM foo;
+SELECT (b FROM bar;
+SELECT c FROM baz;
+",
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let tree = parsed.tree.unwrap();
+
+        let unparsable = tree.recursive_crawl(
+            const { SyntaxSet::single(SyntaxKind::Unparsable) },
+            false,
+            None,
+            true,
+        );
+        assert_eq!(unparsable.len(), 1);
+        assert_eq!(unparsable[0].raw(), "SELECT (b FROM bar");
+
+        let statements = tree.children(const { SyntaxSet::single(SyntaxKind::Statement) });
+        assert_eq!(statements.len(), 2);
+        assert_eq!(statements[1].raw(), "SELECT c FROM baz");
+        assert!(
+            statements[1].child(const { SyntaxSet::single(SyntaxKind::SelectStatement) }).is_some()
+        );
+
+        assert_eq!(parsed.violations.len(), 1);
+        assert_eq!(
+            parsed.violations[0].description,
+            "Found unparsable section: \"SELECT (b FROM bar\""
+        );
+        assert_eq!((parsed.violations[0].line_no, parsed.violations[0].line_pos), (2, 1));
+    }
+
     #[test]
     fn test_linter_empty_file() {
-        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
         let parsed = linter.parse_string("", None, None, None).unwrap();
 
         assert!(parsed.violations.is_empty());
     }
 
+    #[test]
+    fn test_linter_source_str_of() {
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
+        let parsed = linter
+            .parse_string(
+                "SELECT a\nFROM foo\nWHERE a   =  1 -- keep\n  AND b = 2\n",
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let tree = parsed.tree.as_ref().unwrap();
+
+        let where_clause = tree.recursive_crawl(
+            const { SyntaxSet::single(SyntaxKind::WhereClause) },
+            true,
+            None,
+            true,
+        );
+        assert_eq!(where_clause.len(), 1);
+        assert_eq!(
+            parsed.source_str_of(&where_clause[0]),
+            Some("WHERE a   =  1 -- keep\n  AND b = 2")
+        );
+    }
+
     // test__linter__mask_templated_violations
     // test__linter__encoding
     // test_delayed_exception
@@ -719,7 +1050,7 @@ mod tests {
         "
         .to_string();
 
-        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
         let _parsed = linter.parse_string(&sql, None, None, None).unwrap();
     }
 
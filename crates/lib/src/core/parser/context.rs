@@ -40,6 +40,8 @@ pub struct ParseContext<'a> {
     loc_keys: IndexSet<LocKeyData>,
     parse_cache: FxHashMap<Cache, MatchResult>,
     pub(crate) indentation_config: AHashMap<String, bool>,
+    /// Whether to resume parsing after a statement which can't be parsed.
+    pub(crate) recover_from_parse_errors: bool,
 }
 
 impl<'a> ParseContext<'a> {
@@ -50,6 +52,7 @@ impl<'a> ParseContext<'a> {
             loc_keys: IndexSet::default(),
             parse_cache: FxHashMap::default(),
             indentation_config,
+            recover_from_parse_errors: false,
         }
     }
 
@@ -63,7 +66,10 @@ impl<'a> ParseContext<'a> {
         let indentation_config: AHashMap<_, _> =
             indentation_config.iter().map(|(key, value)| (key.clone(), value.to_bool())).collect();
 
-        Self::new(dialect, indentation_config)
+        let mut parse_context = Self::new(dialect, indentation_config);
+        parse_context.recover_from_parse_errors =
+            config.raw["core"]["recover_from_parse_errors"].to_bool();
+        parse_context
     }
 
     pub(crate) fn deeper_match<T>(
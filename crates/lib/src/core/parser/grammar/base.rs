@@ -218,6 +218,16 @@ impl Nothing {
 impl Segment for Nothing {}
 
 impl Matchable for Nothing {
+    // Nothing never matches, so it can be skipped wherever simple matchers are
+    // required, e.g. when a stubbed out segment is used as a terminator.
+    fn simple(
+        &self,
+        _parse_context: &ParseContext,
+        _crumbs: Option<Vec<&str>>,
+    ) -> Option<(AHashSet<String>, SyntaxSet)> {
+        Some((AHashSet::new(), SyntaxSet::EMPTY))
+    }
+
     fn match_segments(
         &self,
         _segments: &[ErasedSegment],
@@ -41,7 +41,7 @@ pub struct TemplateElement<'a> {
     matcher: Info,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 struct Info {
     name: &'static str,
     constructor: fn(&str, PositionMarker) -> ErasedSegment,
@@ -57,6 +57,33 @@ impl<'a> TemplateElement<'a> {
         }
     }
 
+    pub fn name(&self) -> &'static str {
+        self.matcher.name
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// The byte range of this element in the templated string.
+    pub fn template_slice(&self) -> Range<usize> {
+        self.template_slice.clone()
+    }
+
+    /// This element, moved to `template_slice` of a new templated string
+    /// which contains the same text there.
+    fn rebase<'b>(
+        &self,
+        templated_str: &'b str,
+        template_slice: Range<usize>,
+    ) -> TemplateElement<'b> {
+        TemplateElement {
+            raw: Cow::Borrowed(&templated_str[template_slice.clone()]),
+            template_slice,
+            matcher: self.matcher,
+        }
+    }
+
     pub fn to_segment(
         &self,
         pos_marker: PositionMarker,
@@ -175,7 +202,7 @@ impl Matcher {
 
     fn trim_match<'a>(&self, matched_str: &'a str) -> Vec<Element<'a>> {
         let Some(trim_post_subdivide) = &self.trim_post_subdivide else {
-            return Vec::new();
+            return vec![Element::new(self.pattern.name, self.pattern.value, matched_str)];
         };
 
         let mk_element =
@@ -199,11 +226,7 @@ impl Matcher {
             } else if end == str_buff.len() {
                 let raw = format!("{}{}", content_buff, &str_buff[..start]);
 
-                elem_buff.push(Element::new(
-                    trim_post_subdivide.name,
-                    trim_post_subdivide.value,
-                    raw,
-                ));
+                elem_buff.push(Element::new(self.pattern.name, self.pattern.value, raw));
                 elem_buff.push(mk_element(&str_buff[start..end]));
 
                 content_buff.clear();
@@ -302,6 +325,15 @@ pub enum StringOrTemplate<'a> {
     Template(TemplatedFile),
 }
 
+/// The result of re-lexing a string after an edit.
+#[derive(Debug)]
+pub struct Relexed<'a> {
+    pub elements: Vec<TemplateElement<'a>>,
+    /// The number of elements which were produced by running the lexer
+    /// matchers, rather than reused from the previous elements.
+    pub relexed: usize,
+}
+
 impl<'a> Lexer<'a> {
     /// Create a new lexer.
     pub fn new(config: &'a FluffConfig, _dialect: Option<Dialect>) -> Self {
@@ -319,7 +351,7 @@ impl<'a> Lexer<'a> {
         // passed in.
 
         let template;
-        let mut str_buff = match raw {
+        let str_buff = match raw {
             StringOrTemplate::String(s) => {
                 template = TemplatedFile::from_string(s.into());
                 s
@@ -331,6 +363,111 @@ impl<'a> Lexer<'a> {
         };
 
         // Lex the string to get a tuple of LexedElement
+        let element_buffer = self.lex_str(str_buff);
+
+        // Map tuple LexedElement to list of TemplateElement.
+        // This adds the template_slice to the object.
+        let templated_buffer = Lexer::map_template_slices(element_buffer, &template);
+        // Turn lexed elements into segments.
+        let segments = self.elements_to_segments(templated_buffer, &template);
+
+        Ok((segments, Vec::new()))
+    }
+
+    /// Lex a string into elements, without building segments.
+    pub fn lex_elements<'b>(&self, raw: &'b str) -> Vec<TemplateElement<'b>> {
+        let mut idx = 0;
+        self.lex_str(raw)
+            .into_iter()
+            .map(|element| {
+                let template_slice = offset_slice(idx, element.text.len());
+                idx += element.text.len();
+                TemplateElement::from_element(element, template_slice)
+            })
+            .collect()
+    }
+
+    /// Re-lex a string after an edit, reusing the previous elements outside
+    /// of the edited region.
+    ///
+    /// `previous` are the elements of the string before the edit, and `edit`
+    /// is the byte range of that string which was replaced by
+    /// `inserted_len` bytes to give `raw`.
+    ///
+    /// Lexing restarts from the element touching the start of the edit, and
+    /// stops as soon as it reaches an element boundary of the previous
+    /// elements after the edit, since the rest of the string is unchanged.
+    /// This assumes that the elements before the edit don't depend on the
+    /// text after them, which holds unless the edit closes a construct which
+    /// previously failed to lex, such as an unterminated block comment.
+    pub fn relex_elements<'b>(
+        &self,
+        previous: &[TemplateElement<'_>],
+        raw: &'b str,
+        edit: Range<usize>,
+        inserted_len: usize,
+    ) -> Relexed<'b> {
+        let lexer_matchers = self.config.get_dialect().lexer_matchers();
+        let edit_end = edit.start + inserted_len;
+        let shift = |idx: usize| idx + inserted_len - edit.len();
+
+        let first = previous
+            .iter()
+            .position(|element| element.template_slice.end >= edit.start)
+            .unwrap_or(previous.len());
+        let mut elements: Vec<_> = previous[..first]
+            .iter()
+            .map(|element| element.rebase(raw, element.template_slice()))
+            .collect();
+
+        let mut idx = previous.get(first).map_or(0, |element| element.template_slice.start);
+        let mut relexed = 0;
+        let mut rest = first;
+
+        while idx < raw.len() {
+            // Skip to the first previous element which starts after the edit, and not
+            // before the current position.
+            while rest < previous.len()
+                && (previous[rest].template_slice.start < edit.end
+                    || shift(previous[rest].template_slice.start) < idx)
+            {
+                rest += 1;
+            }
+
+            if idx >= edit_end
+                && rest < previous.len()
+                && shift(previous[rest].template_slice.start) == idx
+            {
+                elements.extend(previous[rest..].iter().map(|element| {
+                    let template_slice = element.template_slice();
+                    element.rebase(raw, shift(template_slice.start)..shift(template_slice.end))
+                }));
+                return Relexed { elements, relexed };
+            }
+
+            let Some(res) = lexer_matchers
+                .iter()
+                .map(|matcher| matcher.matches(&raw[idx..]))
+                .find(Match::is_non_empty)
+            else {
+                // Nothing matched, so fall back to lexing the whole string.
+                let elements = self.lex_elements(raw);
+                return Relexed { relexed: elements.len(), elements };
+            };
+
+            for element in res.elements {
+                let template_slice = offset_slice(idx, element.text.len());
+                idx += element.text.len();
+                relexed += 1;
+                elements.push(TemplateElement::from_element(element, template_slice));
+            }
+        }
+
+        Relexed { elements, relexed }
+    }
+
+    /// Lex a string into elements, matching with the dialect lexer matchers.
+    fn lex_str<'b>(&self, mut str_buff: &'b str) -> Vec<Element<'b>> {
         let mut element_buffer: Vec<Element> = Vec::new();
         let lexer_matchers = self.config.get_dialect().lexer_matchers();
 
@@ -352,13 +489,7 @@ impl<'a> Lexer<'a> {
             element_buffer.append(&mut resort_res.elements);
         }
 
-        // Map tuple LexedElement to list of TemplateElement.
-        // This adds the template_slice to the object.
-        let templated_buffer = Lexer::map_template_slices(element_buffer, &template);
-        // Turn lexed elements into segments.
-        let segments = self.elements_to_segments(templated_buffer, &template);
-
-        Ok((segments, Vec::new()))
+        element_buffer
     }
 
     /// Generate any lexing errors for any un-lex-ables.
@@ -465,22 +596,17 @@ fn iter_segments(
 ) -> Vec<ErasedSegment> {
     let mut result = Vec::with_capacity(lexed_elements.len());
     // An index to track where we've got to in the templated file.
-    let tfs_idx = 0;
+    let mut tfs_idx = 0;
     // We keep a map of previous block locations in case they re-occur.
     // let block_stack = BlockTracker()
     let templated_file_slices = &templated_file.sliced_file;
 
     // Now work out source slices, and add in template placeholders.
     for element in lexed_elements.into_iter() {
-        let consumed_element_length = 0;
+        let mut consumed_element_length = 0;
         let mut stashed_source_idx = None;
 
-        for (mut tfs_idx, tfs) in templated_file_slices
-            .iter()
-            .skip(tfs_idx)
-            .enumerate()
-            .map(|(i, tfs)| (i + tfs_idx, tfs))
-        {
+        while let Some(tfs) = templated_file_slices.get(tfs_idx) {
             // Is it a zero slice?
             if is_zero_slice(tfs.templated_slice.clone()) {
                 let _slice = if tfs_idx + 1 < templated_file_slices.len() {
@@ -491,22 +617,27 @@ fn iter_segments(
 
                 _handle_zero_length_slice();
 
+                tfs_idx += 1;
                 continue;
             }
 
             if tfs.slice_type == "literal" {
-                let tfs_offset = tfs.source_slice.start - tfs.templated_slice.start;
+                // Literal slices have the same length in both files, so positions
+                // just shift across.
+                let to_source = |templated_idx: usize| {
+                    templated_idx + tfs.source_slice.start - tfs.templated_slice.start
+                };
 
                 // NOTE: Greater than OR EQUAL, to include the case of it matching
                 // length exactly.
                 if element.template_slice.end <= tfs.templated_slice.end {
                     let slice_start = stashed_source_idx.unwrap_or_else(|| {
-                        element.template_slice.start + consumed_element_length + tfs_offset
+                        to_source(element.template_slice.start + consumed_element_length)
                     });
 
                     result.push(element.to_segment(
                         PositionMarker::new(
-                            slice_start..element.template_slice.end + tfs_offset,
+                            slice_start..to_source(element.template_slice.end),
                             element.template_slice.clone(),
                             templated_file.clone(),
                             None,
@@ -516,9 +647,8 @@ fn iter_segments(
                     ));
 
                     // If it was an exact match, consume the templated element too.
-                    #[allow(unused_assignments)]
                     if element.template_slice.end == tfs.templated_slice.end {
-                        tfs_idx += 1
+                        tfs_idx += 1;
                     }
                     // In any case, we're done with this element. Move on
                     break;
@@ -528,6 +658,7 @@ fn iter_segments(
                     // NOTE: If the rest of the logic works, this should never
                     // happen.
                     // lexer_logger.debug("     NOTE: Missed Skip")  # pragma: no cover
+                    tfs_idx += 1;
                     continue;
                 } else {
                     // This means that the current lexed element spans across
@@ -546,12 +677,13 @@ fn iter_segments(
                             panic!("Found literal whitespace with stashed idx!")
                         }
 
-                        let incremental_length =
-                            tfs.templated_slice.end - element.template_slice.start;
+                        let incremental_length = tfs.templated_slice.end
+                            - element.template_slice.start
+                            - consumed_element_length;
                         result.push(element.to_segment(
                             PositionMarker::new(
-                                element.template_slice.start + consumed_element_length + tfs_offset
-                                    ..tfs.templated_slice.end + tfs_offset,
+                                to_source(element.template_slice.start + consumed_element_length)
+                                    ..to_source(tfs.templated_slice.end),
                                 element.template_slice.clone(),
                                 templated_file.clone(),
                                 None,
@@ -559,22 +691,53 @@ fn iter_segments(
                             ),
                             offset_slice(consumed_element_length, incremental_length).into(),
                         ));
-                    } else {
+                        consumed_element_length += incremental_length;
+                    } else if stashed_source_idx.is_none() {
                         // We can't split it. We're going to end up yielding a segment
                         // which spans multiple slices. Stash the type, and if we haven't
                         // set the start yet, stash it too.
                         // lexer_logger.debug("     Spilling over literal slice.")
-                        if stashed_source_idx.is_none() {
-                            stashed_source_idx = (element.template_slice.start + tfs_idx).into();
-                            // lexer_logger.debug(
-                            //     "     Stashing a source start. %s", stashed_source_idx
-                            // )
-                            continue;
-                        }
+                        stashed_source_idx =
+                            to_source(element.template_slice.start + consumed_element_length)
+                                .into();
                     }
+
+                    tfs_idx += 1;
+                    continue;
+                }
+            } else {
+                // Found a templated slice. Code rendered by the templater can't be
+                // mapped any finer than the tag which produced it, so point at the
+                // whole tag.
+                if element.template_slice.end <= tfs.templated_slice.end {
+                    let slice_start = stashed_source_idx.unwrap_or(tfs.source_slice.start);
+
+                    result.push(element.to_segment(
+                        PositionMarker::new(
+                            slice_start..tfs.source_slice.end,
+                            element.template_slice.clone(),
+                            templated_file.clone(),
+                            None,
+                            None,
+                        ),
+                        Some(consumed_element_length..element.raw.len()),
+                    ));
+
+                    // If it's the end of the templated slice, consume the slice.
+                    if element.template_slice.end == tfs.templated_slice.end {
+                        tfs_idx += 1;
+                    }
+                    break;
                 }
-            } else if matches!(tfs.slice_type.as_str(), "templated" | "block_start") {
-                unimplemented!();
+
+                // The element runs on past the end of this slice, so stash where
+                // it started in the source and carry on into the next one.
+                if stashed_source_idx.is_none() {
+                    stashed_source_idx = tfs.source_slice.start.into();
+                }
+
+                tfs_idx += 1;
+                continue;
             }
         }
     }
@@ -589,6 +752,7 @@ fn _handle_zero_length_slice() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::config::Value;
 
     /// Assert that a matcher does or doesn't work on a string.
     ///
@@ -631,6 +795,33 @@ mod tests {
         assert_eq!(res.elements.len(), 3);
     }
 
+    #[test]
+    fn test_parser_lexer_trim_post_subdivide_keeps_content_kind() {
+        let matcher =
+            Matcher::regex("block_comment", r"\/\*([^\*]|\*(?!\/))*\*\/", |_, _| unimplemented!())
+                .subdivider(Pattern::regex("newline", r"\r\n|\n", |_, _| unimplemented!()))
+                .post_subdivide(Pattern::regex(
+                    "whitespace",
+                    r"[^\S\r\n]+",
+                    |_, _| unimplemented!(),
+                ));
+
+        let res = matcher.matches("/* note  \n  more */");
+        let elements =
+            res.elements.iter().map(|elem| (elem.name, elem.text.as_ref())).collect::<Vec<_>>();
+
+        assert_eq!(
+            elements,
+            [
+                ("block_comment", "/* note"),
+                ("whitespace", "  "),
+                ("newline", "\n"),
+                ("whitespace", "  "),
+                ("block_comment", "more */"),
+            ]
+        );
+    }
+
     /// Test the RegexLexer.
     #[test]
     fn test_parser_lexer_regex() {
@@ -681,4 +872,95 @@ mod tests {
         assert_eq!(res.elements.len(), 5);
         assert_eq!(res.elements[2].text, "#..#");
     }
+
+    fn assert_same_elements(left: &[TemplateElement], right: &[TemplateElement]) {
+        let summary = |elements: &[TemplateElement]| {
+            elements
+                .iter()
+                .map(|it| (it.name(), it.raw().to_owned(), it.template_slice()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(summary(left), summary(right));
+    }
+
+    #[test]
+    fn test_parser_lexer_relex_single_character_edit() {
+        let config = FluffConfig::default();
+        let lexer = Lexer::new(&config, None);
+
+        let before = "SELECT a, b FROM foo WHERE c = 1;\n".repeat(200);
+        let previous = lexer.lex_elements(&before);
+
+        // Replace the `a` on line 100 with `x`.
+        let start = before.len() / 2 + "SELECT ".len();
+        let mut after = before.clone();
+        after.replace_range(start..start + 1, "x");
+
+        let relexed = lexer.relex_elements(&previous, &after, start..start + 1, 1);
+
+        assert_same_elements(&relexed.elements, &lexer.lex_elements(&after));
+        assert!(relexed.relexed < 5, "relexed {} elements", relexed.relexed);
+        assert!(relexed.relexed * 100 < previous.len());
+    }
+
+    #[test]
+    fn test_parser_lexer_relex_merges_elements() {
+        let config = FluffConfig::default();
+        let lexer = Lexer::new(&config, None);
+
+        let before = "SELECT ab + c FROM foo";
+        let previous = lexer.lex_elements(before);
+
+        // Deleting ` + ` merges `ab` and `c` into a single identifier.
+        let after = "SELECT abc FROM foo";
+        let relexed = lexer.relex_elements(&previous, after, 9..12, 0);
+        assert_same_elements(&relexed.elements, &lexer.lex_elements(after));
+
+        // Inserting at the very end of the string.
+        let after = "SELECT ab + c FROM foo_bar";
+        let relexed = lexer.relex_elements(&previous, after, 22..22, 4);
+        assert_same_elements(&relexed.elements, &lexer.lex_elements(after));
+    }
+
+    #[test]
+    fn test_parser_lexer_insert_comment_matcher() {
+        let lex = |config: &FluffConfig| {
+            let lexer = Lexer::new(config, None);
+            let (segments, _) = lexer.lex(StringOrTemplate::String("SELECT 1 # note")).unwrap();
+            segments
+                .into_iter()
+                .map(|segment| (segment.get_type(), segment.raw().to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        let mut config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("dialect".into(), Value::String("sparksql".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(!lex(&config).contains(&(SyntaxKind::InlineComment, "# note".into())));
+
+        config.dialect.insert_comment_matcher(
+            "hash_comment",
+            r"#[^\n]*",
+            SyntaxKind::InlineComment,
+            "inline_comment",
+        );
+        assert_eq!(
+            lex(&config),
+            [
+                (SyntaxKind::Word, "SELECT".into()),
+                (SyntaxKind::Whitespace, " ".into()),
+                (SyntaxKind::NumericLiteral, "1".into()),
+                (SyntaxKind::Whitespace, " ".into()),
+                (SyntaxKind::InlineComment, "# note".into()),
+                (SyntaxKind::EndOfFile, "".into()),
+            ]
+        );
+    }
 }
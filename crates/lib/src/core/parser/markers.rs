@@ -119,7 +119,7 @@ impl PositionMarker {
 
     /// Return the line and position of this marker in the source.
     pub fn source_position(&self) -> (usize, usize) {
-        self.templated_file.get_line_pos_of_char_pos(self.templated_slice.start, true)
+        self.templated_file.get_line_pos_of_char_pos(self.source_slice.start, true)
     }
 
     /// Return the line and position of this marker in the source.
@@ -249,6 +249,7 @@ mod tests {
 
     /// Test that we can correctly infer positions from strings.
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn test_markers_infer_next_position() {
         struct Test {
             raw: String,
@@ -297,6 +298,7 @@ mod tests {
 
     /// Test that we can correctly compare markers.
     #[test]
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
     fn test_markers_comparison() {
         let templ = TemplatedFile::from_string("abc".to_string());
 
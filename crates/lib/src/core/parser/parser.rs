@@ -71,7 +71,7 @@ mod tests {
     #[ignore]
     fn test_parser_parse_error() {
         let in_str = "SELECT ;".to_string();
-        let config = FluffConfig::new(<_>::default(), None, None);
+        let config = FluffConfig::new(<_>::default(), None, None).unwrap();
         let linter = Linter::new(config, None, None);
 
         let _ = linter.parse_string(&in_str, None, None, None);
@@ -209,6 +209,52 @@ impl ErasedSegment {
         acc
     }
 
+    /// Find the segments of `types` in this segment and its descendants.
+    ///
+    /// Descendants for which `stop` returns true are still yielded when they
+    /// match, but their own children are not searched. This segment is always
+    /// searched, whatever `stop` returns for it.
+    pub fn recursive_crawl_until(
+        &self,
+        types: SyntaxSet,
+        stop: &impl Fn(&ErasedSegment) -> bool,
+    ) -> Vec<ErasedSegment> {
+        fn crawl(
+            segment: &ErasedSegment,
+            types: SyntaxSet,
+            stop: &impl Fn(&ErasedSegment) -> bool,
+            acc: &mut Vec<ErasedSegment>,
+        ) {
+            for child in segment.segments() {
+                if child.class_types().intersects(&types) {
+                    acc.push(child.clone());
+                }
+
+                if child.descendant_type_set().intersects(&types) && !stop(child) {
+                    crawl(child, types, stop, acc);
+                }
+            }
+        }
+
+        let mut acc = Vec::new();
+        if self.class_types().intersects(&types) {
+            acc.push(self.clone());
+        }
+        crawl(self, types, stop, &mut acc);
+        acc
+    }
+
+    /// Iterate over the descendants of this segment which are of the given
+    /// kind. When `recurse_into` is false, the children of a matching segment
+    /// are not searched.
+    pub fn children_of_kind(
+        &self,
+        kind: SyntaxKind,
+        recurse_into: bool,
+    ) -> impl Iterator<Item = ErasedSegment> {
+        self.recursive_crawl(SyntaxSet::single(kind), recurse_into, None, false).into_iter()
+    }
+
     pub fn raw_segments_with_ancestors(&self) -> &[(ErasedSegment, Vec<PathStep>)] {
         self.value.raw_segments_with_ancestors().get_or_init(|| {
             let mut buffer: Vec<(ErasedSegment, Vec<PathStep>)> =
@@ -1498,6 +1544,8 @@ pub fn pos_marker(segments: &[ErasedSegment]) -> PositionMarker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::config::FluffConfig;
+    use crate::core::linter::linter::Linter;
     use crate::core::parser::segments::raw::{RawSegment, RawSegmentArgs};
     use crate::core::parser::segments::test_functions::{raw_seg, raw_segments};
 
@@ -1615,4 +1663,54 @@ mod tests {
         assert!(segment.is_type(SyntaxKind::Unlexable));
         assert!(!segment.is_type(SyntaxKind::Whitespace));
     }
+
+    #[test]
+    fn test_parser_base_segments_children_of_kind() {
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
+        let tree =
+            linter.parse_string("SELECT a, b FROM t", None, None, None).unwrap().tree.unwrap();
+
+        let references = tree
+            .children_of_kind(SyntaxKind::ColumnReference, true)
+            .map(|segment| segment.raw().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(references, ["a", "b"]);
+
+        assert_eq!(tree.children_of_kind(SyntaxKind::SelectClause, false).count(), 1);
+        assert_eq!(tree.children_of_kind(SyntaxKind::NakedIdentifier, true).count(), 3);
+    }
+
+    #[test]
+    fn test_parser_base_segments_recursive_crawl_until() {
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
+        let tree = linter
+            .parse_string(
+                "SELECT a FROM (SELECT b FROM (SELECT c FROM t) AS u) AS v",
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .tree
+            .unwrap();
+        let select =
+            tree.recursive_crawl_until(SyntaxSet::single(SyntaxKind::SelectStatement), &|_| false);
+        assert_eq!(select.len(), 3);
+
+        let clauses = select[0]
+            .recursive_crawl_until(SyntaxSet::single(SyntaxKind::SelectClause), &|segment| {
+                segment.is_type(SyntaxKind::SelectStatement)
+            })
+            .into_iter()
+            .map(|segment| segment.raw().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(clauses, ["SELECT a"]);
+
+        // Nested statements are yielded, but not searched.
+        let statements = select[0]
+            .recursive_crawl_until(SyntaxSet::single(SyntaxKind::SelectStatement), &|segment| {
+                segment.is_type(SyntaxKind::SelectStatement)
+            });
+        assert_eq!(statements.len(), 2);
+    }
 }
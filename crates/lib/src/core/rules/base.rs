@@ -26,6 +26,8 @@ pub struct LintResult {
     memory: Option<AHashMap<String, String>>, // Adjust type as needed
     description: Option<String>,
     source: String,
+    /// Report the result as a warning rather than as a failure.
+    pub warning: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Copy, Hash, Eq, AsRefStr)]
@@ -38,6 +40,8 @@ pub enum RuleGroups {
     Capitalisation,
     Convention,
     Layout,
+    Performance,
+    Portability,
     References,
     Structure,
 }
@@ -52,7 +56,14 @@ impl LintResult {
     ) -> Self {
         // let fixes = fixes.into_iter().filter(|f| !f.is_trivial()).collect();
 
-        LintResult { anchor, fixes, memory, description, source: source.unwrap_or_default() }
+        LintResult {
+            anchor,
+            fixes,
+            memory,
+            description,
+            source: source.unwrap_or_default(),
+            warning: false,
+        }
     }
 
     pub fn to_linting_error(&self, rule: ErasedRule) -> Option<SQLLintError> {
@@ -62,7 +73,10 @@ impl LintResult {
             self.description.clone().unwrap_or_else(|| rule.description().to_string());
 
         SQLLintError::new(description.as_str(), anchor)
-            .config(|this| this.rule = rule.into())
+            .config(|this| {
+                this.rule = rule.into();
+                this.warning = self.warning;
+            })
             .into()
     }
 }
@@ -526,12 +540,13 @@ impl RuleSet {
         expanded_rule_set
     }
 
-    pub(crate) fn get_rulepack(&self, config: &FluffConfig) -> RulePack {
-        let reference_map = self.rule_reference_map();
-        let rules = config.get_section("rules");
-        let keylist = self.register.keys();
-        let mut instantiated_rules = Vec::with_capacity(keylist.len());
-
+    /// The codes of the rules selected by the allowlist and denylist in
+    /// `config`, in registration order.
+    fn selected_codes(
+        &self,
+        config: &FluffConfig,
+        reference_map: &AHashMap<&'static str, AHashSet<&'static str>>,
+    ) -> Vec<&'static str> {
         let allowlist: Vec<String> = match config.get("rule_allowlist", "core").as_array() {
             Some(array) => array.iter().map(|it| it.as_string().unwrap().to_owned()).collect(),
             None => self.register.keys().map(|it| it.to_string()).collect(),
@@ -542,13 +557,27 @@ impl RuleSet {
             None => Vec::new(),
         };
 
-        let expanded_allowlist = self.expand_rule_refs(allowlist, &reference_map);
-        let expanded_denylist = self.expand_rule_refs(denylist, &reference_map);
+        let expanded_allowlist = self.expand_rule_refs(allowlist, reference_map);
+        let expanded_denylist = self.expand_rule_refs(denylist, reference_map);
 
-        let keylist: Vec<_> = keylist
-            .into_iter()
-            .filter(|&&r| expanded_allowlist.contains(r) && !expanded_denylist.contains(r))
-            .collect();
+        self.register
+            .keys()
+            .copied()
+            .filter(|r| expanded_allowlist.contains(r) && !expanded_denylist.contains(r))
+            .collect()
+    }
+
+    /// Whether `config` selects the rule with the given code.
+    pub(crate) fn is_selected(&self, config: &FluffConfig, code: &str) -> bool {
+        let reference_map = self.rule_reference_map();
+        self.selected_codes(config, &reference_map).contains(&code)
+    }
+
+    pub(crate) fn get_rulepack(&self, config: &FluffConfig) -> RulePack {
+        let reference_map = self.rule_reference_map();
+        let rules = config.get_section("rules");
+        let keylist = self.selected_codes(config, &reference_map);
+        let mut instantiated_rules = Vec::with_capacity(keylist.len());
 
         for code in keylist {
             let rule = self.register[code].rule_class.clone();
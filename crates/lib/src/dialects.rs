@@ -127,6 +127,7 @@ pub enum SyntaxKind {
     WhenClause,
     ElseClause,
     WhereClause,
+    PrewhereClause,
     SetOperator,
     ValuesClause,
     EmptyStructLiteral,
@@ -569,6 +570,7 @@ pub enum SyntaxKind {
     ValidationModeOption,
     EndExcludeBracket,
     IdentifierList,
+    IdentifierClause,
     TemplateLoop,
     ColonDelimiter,
     SqlcmdOperator,
@@ -576,6 +578,11 @@ pub enum SyntaxKind {
     TableEndClauseSegment,
     PragmaStatement,
     PragmaReference,
+    ExecuteImmediateStatement,
+    CreateQuotaStatement,
+    CreateFlowStatement,
+    SettingsClause,
+    OffsetClause,
 }
 
 impl SyntaxKind {
@@ -1463,6 +1463,7 @@ pub fn raw_dialect() -> Dialect {
                 Ref::new("SetOperatorSegment").boxed(),
                 Ref::new("WithNoSchemaBindingClauseSegment").boxed(),
                 Ref::new("WithDataClauseSegment").boxed(),
+                Ref::new("OffsetClauseSegment").boxed(),
                 Ref::keyword("FETCH").boxed(),
             ])
             .to_matchable()
@@ -1480,6 +1481,7 @@ pub fn raw_dialect() -> Dialect {
                 Ref::keyword("QUALIFY").boxed(),
                 Ref::keyword("WINDOW").boxed(),
                 Ref::keyword("OVERLAPS").boxed(),
+                Ref::new("OffsetClauseSegment").boxed(),
                 Ref::keyword("FETCH").boxed(),
             ])
             .to_matchable()
@@ -1494,6 +1496,7 @@ pub fn raw_dialect() -> Dialect {
                 Ref::keyword("HAVING").boxed(),
                 Ref::keyword("QUALIFY").boxed(),
                 Ref::keyword("WINDOW").boxed(),
+                Ref::new("OffsetClauseSegment").boxed(),
                 Ref::keyword("FETCH").boxed(),
             ])
             .to_matchable()
@@ -1507,6 +1510,7 @@ pub fn raw_dialect() -> Dialect {
                 Ref::keyword("LIMIT").boxed(),
                 Ref::keyword("QUALIFY").boxed(),
                 Ref::keyword("WINDOW").boxed(),
+                Ref::new("OffsetClauseSegment").boxed(),
                 Ref::keyword("FETCH").boxed(),
             ])
             .to_matchable()
@@ -1521,6 +1525,7 @@ pub fn raw_dialect() -> Dialect {
                 Ref::keyword("WINDOW").boxed(),
                 Ref::new("FrameClauseUnitGrammar").boxed(),
                 Ref::keyword("SEPARATOR").boxed(),
+                Ref::new("OffsetClauseSegment").boxed(),
                 Ref::keyword("FETCH").boxed(),
             ])
             .to_matchable()
@@ -1955,7 +1960,13 @@ pub fn raw_dialect() -> Dialect {
                         Sequence::new(vec_of_erased![
                             Ref::new("FunctionNameSegment").exclude(one_of(vec_of_erased![
                                 Ref::new("DatePartFunctionNameSegment"),
-                                Ref::new("ValuesClauseSegment")
+                                Ref::new("ValuesClauseSegment"),
+                                // `EXISTS (SELECT ...)` is a predicate rather than a function
+                                // call.
+                                Sequence::new(vec_of_erased![
+                                    Ref::keyword("EXISTS"),
+                                    Bracketed::new(vec_of_erased![Ref::new("SelectableGrammar")])
+                                ])
                             ])),
                             Bracketed::new(vec_of_erased![
                                 Ref::new("FunctionContentsGrammar").optional()
@@ -2042,7 +2053,10 @@ pub fn raw_dialect() -> Dialect {
                         ]),
                         Sequence::new(vec_of_erased![
                             Ref::new("CommaSegment"),
-                            Ref::new("NumericLiteralSegment"),
+                            one_of(vec_of_erased![
+                                Ref::new("NumericLiteralSegment"),
+                                Ref::new("ExpressionSegment"),
+                            ]),
                         ]),
                     ])
                     .config(|this| this.optional()),
@@ -2183,7 +2197,27 @@ pub fn raw_dialect() -> Dialect {
                     one_of(vec_of_erased![Ref::keyword("FIRST"), Ref::keyword("NEXT")]),
                     Ref::new("NumericLiteralSegment").optional(),
                     one_of(vec_of_erased![Ref::keyword("ROW"), Ref::keyword("ROWS")]),
-                    Ref::keyword("ONLY"),
+                    one_of(vec_of_erased![
+                        Ref::keyword("ONLY"),
+                        Sequence::new(vec_of_erased![Ref::keyword("WITH"), Ref::keyword("TIES")]),
+                    ]),
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "OffsetClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::OffsetClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("OFFSET"),
+                    one_of(vec_of_erased![
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("ExpressionSegment"),
+                    ]),
+                    one_of(vec_of_erased![Ref::keyword("ROW"), Ref::keyword("ROWS")]),
                 ])
                 .to_matchable(),
             )
@@ -2373,6 +2407,30 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            // Declarative partitioning of a table, e.g. `PARTITION BY RANGE (col)`.
+            "PartitionByClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::PartitionBySegment,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("PARTITION"),
+                    Ref::keyword("BY"),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![Ref::keyword("RANGE"), Ref::keyword("LIST")]),
+                            Ref::keyword("COLUMNS").optional()
+                        ]),
+                        Ref::keyword("HASH")
+                    ]),
+                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
+                        "ExpressionSegment"
+                    )])])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "TableEndClauseSegment".into(),
             NodeMatcher::new(SyntaxKind::TableEndClause, Nothing::new().to_matchable())
@@ -2778,6 +2836,20 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "WithinGroupClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::WithingroupClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("WITHIN"),
+                    Ref::keyword("GROUP"),
+                    Bracketed::new(vec_of_erased![Ref::new("OrderByClauseSegment").optional()])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "NamedWindowSegment".into(),
             NodeMatcher::new(
@@ -3129,6 +3201,7 @@ pub fn raw_dialect() -> Dialect {
                 SyntaxKind::ExplainStatement,
                 Sequence::new(vec_of_erased![
                     Ref::keyword("EXPLAIN"),
+                    Ref::keyword("ANALYZE").optional(),
                     one_of(vec_of_erased![
                         Ref::new("SelectableGrammar"),
                         Ref::new("InsertStatementSegment"),
@@ -3615,8 +3688,9 @@ pub fn raw_dialect() -> Dialect {
                             )])]),
                             Ref::new("CommentClauseSegment").optional()
                         ]),
-                        // Create AS syntax:
+                        // Create AS syntax, optionally renaming the columns:
                         Sequence::new(vec_of_erased![
+                            Ref::new("BracketedColumnReferenceListGrammar").optional(),
                             Ref::keyword("AS"),
                             optionally_bracketed(vec_of_erased![Ref::new("SelectableGrammar")])
                         ]),
@@ -3626,6 +3700,7 @@ pub fn raw_dialect() -> Dialect {
                             Ref::new("TableReferenceSegment")
                         ])
                     ]),
+                    Ref::new("PartitionByClauseSegment").optional(),
                     Ref::new("TableEndClauseSegment").optional()
                 ])
                 .to_matchable(),
@@ -4928,6 +5003,13 @@ pub fn raw_dialect() -> Dialect {
                     Ref::keyword("TRUNCATE").boxed(),
                     Ref::keyword("TABLE").optional().boxed(),
                     Ref::new("TableReferenceSegment").boxed(),
+                    Sequence::new(vec_of_erased![
+                        one_of(vec_of_erased![Ref::keyword("RESTART"), Ref::keyword("CONTINUE")]),
+                        Ref::keyword("IDENTITY")
+                    ])
+                    .config(|this| this.optional())
+                    .boxed(),
+                    Ref::new("DropBehaviorGrammar").optional().boxed(),
                 ])
                 .to_matchable(),
             )
@@ -5144,9 +5226,10 @@ pub fn raw_dialect() -> Dialect {
         ),
         (
             "PostFunctionGrammar".into(),
-            one_of(vec![
-                Ref::new("OverClauseSegment").boxed(),
-                Ref::new("FilterClauseGrammar").boxed(),
+            Sequence::new(vec![
+                Ref::new("WithinGroupClauseSegment").optional().boxed(),
+                Ref::new("FilterClauseGrammar").optional().boxed(),
+                Ref::new("OverClauseSegment").optional().boxed(),
             ])
             .to_matchable()
             .into(),
@@ -5225,29 +5308,8 @@ pub fn raw_dialect() -> Dialect {
                             Ref::new("Tail_Recurse_Expression_A_Grammar").boxed(),
                         ])
                         .boxed(),
-                        // IN grammar with NOT and brackets
-                        Sequence::new(vec![
-                            Ref::keyword("NOT").optional().boxed(),
-                            Ref::keyword("IN").boxed(),
-                            Bracketed::new(vec![
-                                one_of(vec![
-                                    Delimited::new(vec![Ref::new("Expression_A_Grammar").boxed()])
-                                        .boxed(),
-                                    Ref::new("SelectableGrammar").boxed(),
-                                ])
-                                .boxed(),
-                            ])
-                            .config(|this| this.parse_mode(ParseMode::Greedy))
-                            .boxed(),
-                        ])
-                        .boxed(),
-                        // IN grammar with function segment
-                        Sequence::new(vec![
-                            Ref::keyword("NOT").optional().boxed(),
-                            Ref::keyword("IN").boxed(),
-                            Ref::new("FunctionSegment").boxed(),
-                        ])
-                        .boxed(),
+                        // IN grammar with NOT and brackets or a function segment
+                        Ref::new("InOperatorGrammar").boxed(),
                         // IS grammar
                         Sequence::new(vec![
                             Ref::keyword("IS").boxed(),
@@ -5822,7 +5884,8 @@ pub fn get_unordered_select_statement_segment_grammar() -> Arc<dyn Matchable> {
         Ref::new("WithNoSchemaBindingClauseSegment"),
         Ref::new("WithDataClauseSegment"),
         Ref::new("OrderByClauseSegment"),
-        Ref::new("LimitClauseSegment")
+        Ref::new("LimitClauseSegment"),
+        Ref::new("OffsetClauseSegment")
     ])
     .config(|this| {
         this.parse_mode(ParseMode::GreedyOnceStarted);
@@ -5834,6 +5897,7 @@ pub fn select_statement() -> Arc<dyn Matchable> {
     get_unordered_select_statement_segment_grammar().copy(
         Some(vec_of_erased![
             Ref::new("OrderByClauseSegment").optional(),
+            Ref::new("OffsetClauseSegment").optional(),
             Ref::new("FetchClauseSegment").optional(),
             Ref::new("LimitClauseSegment").optional(),
             Ref::new("NamedWindowSegment").optional()
@@ -5945,18 +6009,29 @@ impl FileSegment {
 
         let file_segment = parse_context.dialect().r#ref("FileSegment");
 
-        let match_result = file_segment.match_grammar().unwrap().match_segments(
+        let match_result = match file_segment.match_grammar().unwrap().match_segments(
             &segments[..end_idx as usize],
             start_idx,
             parse_context,
-        )?;
+        ) {
+            Ok(match_result) => match_result,
+            // Leave the whole file to be recovered statement by statement.
+            Err(_) if parse_context.recover_from_parse_errors => MatchResult::empty_at(start_idx),
+            Err(error) => return Err(error),
+        };
 
         let match_span = match_result.span;
         let has_match = match_result.has_match();
         let mut matched = match_result.apply(dialect, segments);
-        let unmatched = &segments[match_span.end as usize..end_idx as usize];
+        let resume_idx = if has_match { match_span.end } else { start_idx };
+        let unmatched = &segments[resume_idx as usize..end_idx as usize];
 
-        let content: &[ErasedSegment] = if !has_match {
+        let content: &[ErasedSegment] = if parse_context.recover_from_parse_errors
+            && !unmatched.is_empty()
+        {
+            Self::recover(dialect, segments, resume_idx, end_idx, parse_context, &mut matched)?;
+            &matched
+        } else if !has_match {
             &[Node::new(
                 dialect,
                 SyntaxKind::Unparsable,
@@ -5983,6 +6058,72 @@ impl FileSegment {
             [&segments[..start_idx as usize], content, &segments[end_idx as usize..]].concat(),
         ))
     }
+
+    /// Parses the segments from `idx` which the file grammar couldn't match,
+    /// one statement at a time. Anything which can't be parsed is wrapped as
+    /// unparsable up to the next delimiter, and parsing resumes after it.
+    fn recover(
+        dialect: DialectKind,
+        segments: &[ErasedSegment],
+        mut idx: u32,
+        end_idx: u32,
+        parse_context: &mut ParseContext,
+        content: &mut Vec<ErasedSegment>,
+    ) -> Result<(), SQLParseError> {
+        let segments_to_end = &segments[..end_idx as usize];
+        let statement = parse_context.dialect().grammar("StatementSegment");
+        let delimiter = parse_context.dialect().grammar("DelimiterGrammar");
+
+        loop {
+            let code_idx =
+                (idx..end_idx).find(|&i| segments[i as usize].is_code()).unwrap_or(end_idx);
+            content.extend_from_slice(&segments[idx as usize..code_idx as usize]);
+            idx = code_idx;
+            if idx == end_idx {
+                return Ok(());
+            }
+
+            let match_result = delimiter.match_segments(segments_to_end, idx, parse_context)?;
+            if match_result.has_match() {
+                idx = match_result.span.end;
+                content.extend(match_result.apply(dialect, segments));
+                continue;
+            }
+
+            if let Ok(match_result) = statement.match_segments(segments_to_end, idx, parse_context)
+            {
+                if match_result.has_match() && match_result.span.end > idx {
+                    idx = match_result.span.end;
+                    content.extend(match_result.apply(dialect, segments));
+                    continue;
+                }
+            }
+
+            let mut stop_idx = end_idx;
+            for i in idx + 1..end_idx {
+                if segments[i as usize].is_code()
+                    && delimiter.match_segments(segments_to_end, i, parse_context)?.has_match()
+                {
+                    stop_idx = i;
+                    break;
+                }
+            }
+
+            let unparsable_end =
+                (idx..stop_idx).rev().find(|&i| segments[i as usize].is_code()).unwrap() + 1;
+            content.push(
+                Node::new(
+                    dialect,
+                    SyntaxKind::Unparsable,
+                    segments[idx as usize..unparsable_end as usize].to_vec(),
+                    true,
+                )
+                .to_erased_segment(),
+            );
+            content.extend_from_slice(&segments[unparsable_end as usize..stop_idx as usize]);
+            idx = stop_idx;
+        }
+    }
 }
 
 pub struct FromExpressionElementSegment(pub ErasedSegment);
@@ -6448,7 +6589,7 @@ mod tests {
 
         for (raw, res) in test_cases {
             // Assume FluffConfig and Lexer are defined somewhere in your codebase
-            let config = FluffConfig::new(<_>::default(), None, None);
+            let config = FluffConfig::new(<_>::default(), None, None).unwrap();
 
             let lexer = Lexer::new(&config, None);
 
@@ -6593,7 +6734,8 @@ mod tests {
         ];
 
         for (raw, err_locations) in tests {
-            let lnt = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+            let lnt =
+                Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
             let parsed = lnt.parse_string(raw, None, None, None).unwrap();
             assert!(!parsed.violations.is_empty());
 
@@ -6605,7 +6747,7 @@ mod tests {
 
     #[test]
     fn test_dialect_ansi_is_whitespace() {
-        let lnt = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let lnt = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
         let file_content =
             std::fs::read_to_string("test/fixtures/dialects/ansi/select_in_multiline_comment.sql")
                 .expect("Unable to read file");
@@ -6628,7 +6770,7 @@ mod tests {
                 [1, 5, 8, 11, 15, 17, 19, 23, 24, 26, 29, 31, 33, 34, 35].as_slice(),
             ),
         ];
-        let lnt = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let lnt = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
 
         for (sql_string, meta_loc) in cases {
             let parsed = lnt.parse_string(sql_string, None, None, None).unwrap();
@@ -6652,7 +6794,7 @@ mod tests {
 
     #[test]
     fn base_parse_struct() {
-        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None), None, None);
+        let linter = Linter::new(FluffConfig::new(<_>::default(), None, None).unwrap(), None, None);
 
         let files =
             glob::glob("test/fixtures/dialects/ansi/*.sql").unwrap().flatten().collect_vec();
@@ -317,6 +317,7 @@ GREATEST
 GROUP
 GROUPING
 HANDLER
+HASH
 HAVING
 HEADER
 HEAP
@@ -403,6 +404,7 @@ LIKE
 LIMIT
 LINENO
 LINES
+LIST
 LISTEN
 LN
 LOAD
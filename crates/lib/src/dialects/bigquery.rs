@@ -2718,7 +2718,8 @@ mod tests {
                 .into(),
                 None,
                 None,
-            ),
+            )
+            .unwrap(),
             None,
             None,
         );
@@ -53,9 +53,36 @@ pub fn clickhouse_dialect() -> Dialect {
         .to_matchable(),
     );
 
+    clickhouse_dialect.replace_grammar(
+        "SamplingExpressionSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("SAMPLE"),
+            Ref::new("SampleRatioGrammar"),
+            Sequence::new(vec_of_erased![Ref::keyword("OFFSET"), Ref::new("SampleRatioGrammar")])
+                .config(|this| this.optional()),
+        ])
+        .to_matchable(),
+    );
+
+    clickhouse_dialect.add([(
+        "SampleRatioGrammar".into(),
+        one_of(vec_of_erased![
+            Sequence::new(vec_of_erased![
+                Ref::new("NumericLiteralSegment"),
+                Ref::new("DivideSegment"),
+                Ref::new("NumericLiteralSegment"),
+            ]),
+            Ref::new("NumericLiteralSegment"),
+        ])
+        .to_matchable()
+        .into(),
+    )]);
+
     clickhouse_dialect.replace_grammar(
         "JoinClauseSegment",
         one_of(vec_of_erased![Sequence::new(vec_of_erased![
+            // `GLOBAL` distributes the right-hand table to every remote server.
+            Ref::keyword("GLOBAL").optional(),
             Ref::new("JoinTypeKeywords").optional(),
             Ref::new("JoinKeywordsGrammar"),
             MetaSegment::indent(),
@@ -83,6 +110,309 @@ pub fn clickhouse_dialect() -> Dialect {
         .to_matchable(),
     );
 
+    clickhouse_dialect.add([
+        (
+            "PrewhereClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::PrewhereClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("PREWHERE"),
+                    MetaSegment::implicit_indent(),
+                    optionally_bracketed(vec_of_erased![Ref::new("ExpressionSegment")]),
+                    MetaSegment::dedent(),
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "FromClauseTerminatorGrammar".into(),
+            clickhouse_dialect
+                .grammar("FromClauseTerminatorGrammar")
+                .copy(
+                    Some(vec_of_erased![Ref::keyword("PREWHERE"), Ref::keyword("SETTINGS")]),
+                    None,
+                    None,
+                    None,
+                    Vec::new(),
+                    false,
+                )
+                .into(),
+        ),
+    ]);
+
+    clickhouse_dialect.add([(
+        "InOperatorGrammar".into(),
+        Sequence::new(vec_of_erased![
+            Ref::keyword("GLOBAL").optional(),
+            Ref::keyword("NOT").optional(),
+            Ref::keyword("IN"),
+            one_of(vec_of_erased![
+                Bracketed::new(vec_of_erased![one_of(vec_of_erased![
+                    Delimited::new(vec_of_erased![Ref::new("Expression_A_Grammar")]),
+                    Ref::new("SelectableGrammar"),
+                ])])
+                .config(|this| this.parse_mode(ParseMode::Greedy)),
+                // E.g. `x IN [1, 2, 3]`
+                Ref::new("ArrayLiteralSegment"),
+                Ref::new("FunctionSegment"),
+            ]),
+        ])
+        .to_matchable()
+        .into(),
+    )]);
+
+    clickhouse_dialect.replace_grammar(
+        "UnorderedSelectStatementSegment",
+        ansi::get_unordered_select_statement_segment_grammar().copy(
+            Some(vec_of_erased![Ref::new("PrewhereClauseSegment").optional()]),
+            None,
+            Some(Ref::new("WhereClauseSegment").optional().to_matchable()),
+            None,
+            Vec::new(),
+            false,
+        ),
+    );
+
+    clickhouse_dialect.replace_grammar(
+        "SelectStatementSegment",
+        ansi::select_statement()
+            .copy(
+                Some(vec_of_erased![Ref::new("PrewhereClauseSegment").optional()]),
+                None,
+                Some(Ref::new("WhereClauseSegment").optional().to_matchable()),
+                None,
+                Vec::new(),
+                false,
+            )
+            .copy(
+                Some(vec_of_erased![Ref::new("SettingsClauseSegment").optional()]),
+                None,
+                None,
+                None,
+                Vec::new(),
+                false,
+            ),
+    );
+
+    clickhouse_dialect.add([(
+        "SettingsClauseSegment".into(),
+        NodeMatcher::new(
+            SyntaxKind::SettingsClause,
+            Sequence::new(vec_of_erased![
+                Ref::keyword("SETTINGS"),
+                MetaSegment::indent(),
+                Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
+                    Ref::new("NakedIdentifierSegment"),
+                    Ref::new("EqualsSegment"),
+                    one_of(vec_of_erased![
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("QuotedLiteralSegment"),
+                        Ref::new("BooleanLiteralGrammar"),
+                        Ref::new("NakedIdentifierSegment"),
+                    ]),
+                ])]),
+                MetaSegment::dedent(),
+            ])
+            .to_matchable(),
+        )
+        .to_matchable()
+        .into(),
+    )]);
+
+    clickhouse_dialect.replace_grammar(
+        "AlterTableStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("ALTER"),
+            Ref::keyword("TABLE"),
+            Ref::new("TableReferenceSegment"),
+            Ref::new("OnClusterClauseSegment").optional(),
+            Delimited::new(vec_of_erased![one_of(vec_of_erased![
+                Ref::new("AlterTablePartitionGrammar"),
+                Ref::new("AlterTableOptionsGrammar"),
+            ])]),
+        ])
+        .to_matchable(),
+    );
+
+    clickhouse_dialect.add([
+        (
+            // PARTITION expr | PARTITION ID 'id' | PARTITION ALL | PART 'name'
+            "PartitionOrPartGrammar".into(),
+            one_of(vec_of_erased![
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("PARTITION"),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("ID"),
+                            Ref::new("QuotedLiteralSegment"),
+                        ]),
+                        Ref::keyword("ALL"),
+                        Ref::new("ExpressionSegment"),
+                    ]),
+                ]),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("PART"),
+                    Ref::new("QuotedLiteralSegment"),
+                ]),
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "AlterTablePartitionGrammar".into(),
+            one_of(vec_of_erased![
+                // DETACH | DROP [DETACHED] PARTITION|PART
+                Sequence::new(vec_of_erased![
+                    one_of(vec_of_erased![
+                        Ref::keyword("DETACH"),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("DROP"),
+                            Ref::keyword("DETACHED").optional(),
+                        ]),
+                    ]),
+                    Ref::new("PartitionOrPartGrammar"),
+                ]),
+                // ATTACH PARTITION|PART [FROM table]
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("ATTACH"),
+                    Ref::new("PartitionOrPartGrammar"),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("FROM"),
+                        Ref::new("TableReferenceSegment"),
+                    ])
+                    .config(|this| this.optional()),
+                ]),
+                // FREEZE | UNFREEZE [PARTITION expr] [WITH NAME 'name']
+                Sequence::new(vec_of_erased![
+                    one_of(vec_of_erased![Ref::keyword("FREEZE"), Ref::keyword("UNFREEZE")]),
+                    Ref::new("PartitionOrPartGrammar").optional(),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("WITH"),
+                        Ref::keyword("NAME"),
+                        Ref::new("QuotedLiteralSegment"),
+                    ])
+                    .config(|this| this.optional()),
+                ]),
+                // MOVE PARTITION|PART TO DISK|VOLUME 'name' | TO TABLE table
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("MOVE"),
+                    Ref::new("PartitionOrPartGrammar"),
+                    Ref::keyword("TO"),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![Ref::keyword("DISK"), Ref::keyword("VOLUME")]),
+                            Ref::new("QuotedLiteralSegment"),
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("TABLE"),
+                            Ref::new("TableReferenceSegment"),
+                        ]),
+                    ]),
+                ]),
+            ])
+            .to_matchable()
+            .into(),
+        ),
+    ]);
+
+    // LIMIT [m,] n [OFFSET m] [BY expr, ...]
+    clickhouse_dialect.replace_grammar(
+        "LimitClauseSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("LIMIT"),
+            MetaSegment::indent(),
+            optionally_bracketed(vec_of_erased![one_of(vec_of_erased![
+                Ref::new("NumericLiteralSegment"),
+                Ref::new("ExpressionSegment"),
+            ])]),
+            one_of(vec_of_erased![
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("OFFSET"),
+                    one_of(vec_of_erased![
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("ExpressionSegment"),
+                    ]),
+                ]),
+                Sequence::new(vec_of_erased![
+                    Ref::new("CommaSegment"),
+                    one_of(vec_of_erased![
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("ExpressionSegment"),
+                    ]),
+                ]),
+            ])
+            .config(|this| this.optional()),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("BY"),
+                Delimited::new(vec_of_erased![Ref::new("ExpressionSegment")]),
+            ])
+            .config(|this| this.optional()),
+            MetaSegment::dedent(),
+        ])
+        .to_matchable(),
+    );
+
+    clickhouse_dialect.add([(
+        "WithCubeRollupClauseSegment".into(),
+        NodeMatcher::new(
+            SyntaxKind::WithCubeRollupClause,
+            Sequence::new(vec_of_erased![
+                Ref::keyword("WITH"),
+                one_of(vec_of_erased![Ref::keyword("CUBE"), Ref::keyword("ROLLUP")])
+            ])
+            .to_matchable(),
+        )
+        .to_matchable()
+        .into(),
+    )]);
+
+    // GROUP BY ... [WITH ROLLUP | WITH CUBE] [WITH TOTALS]
+    clickhouse_dialect.replace_grammar(
+        "GroupByClauseSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("GROUP"),
+            Ref::keyword("BY"),
+            one_of(vec_of_erased![
+                Ref::new("CubeRollupClauseSegment"),
+                Sequence::new(vec_of_erased![
+                    MetaSegment::indent(),
+                    Delimited::new(vec_of_erased![one_of(vec_of_erased![
+                        Ref::new("ColumnReferenceSegment"),
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("ExpressionSegment"),
+                    ])])
+                    .config(|this| {
+                        this.terminators = vec_of_erased![
+                            Ref::new("GroupByClauseTerminatorGrammar"),
+                            Ref::keyword("WITH"),
+                        ];
+                    }),
+                    MetaSegment::dedent(),
+                ]),
+            ]),
+            Ref::new("WithCubeRollupClauseSegment").optional(),
+            Sequence::new(vec_of_erased![Ref::keyword("WITH"), Ref::keyword("TOTALS")])
+                .config(|this| this.optional()),
+        ])
+        .to_matchable(),
+    );
+
+    clickhouse_dialect.replace_grammar(
+        "InsertStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("INSERT"),
+            Ref::keyword("INTO"),
+            Ref::keyword("TABLE").optional(),
+            Ref::new("TableReferenceSegment"),
+            Ref::new("BracketedColumnReferenceListGrammar").optional(),
+            Ref::new("SettingsClauseSegment").optional(),
+            one_of(vec_of_erased![Ref::new("SelectableGrammar"), Ref::new("DefaultValuesGrammar")]),
+        ])
+        .to_matchable(),
+    );
+
     clickhouse_dialect.add([
         (
             "SingleIdentifierGrammar".into(),
@@ -135,6 +465,19 @@ pub fn clickhouse_dialect() -> Dialect {
         ),
     ]);
 
+    // Numeric literals may contain `_` between digits and may be hex or binary.
+    clickhouse_dialect.patch_lexer_matchers(vec![Matcher::regex(
+        "numeric_literal",
+        r"(?>0[xX][0-9a-fA-F]+(?:_[0-9a-fA-F]+)*|0[bB][01]+(?:_[01]+)*|\d+(?:_\d+)*\.\d+(?:_\d+)*|\d+(?:_\d+)*\.(?![\.\w])|\.\d+(?:_\d+)*|\d+(?:_\d+)*)(\.?[eE][+-]?\d+)?((?<=\.)|(?=\b))",
+        |slice, marker| {
+            CodeSegment::create(
+                slice,
+                marker.into(),
+                CodeSegmentNewArgs { code_type: SyntaxKind::NumericLiteral, ..Default::default() },
+            )
+        },
+    )]);
+
     clickhouse_dialect.insert_lexer_matchers(
         vec![Matcher::string("lambda", "->", |slice, m| {
             SymbolSegment::create(
@@ -223,6 +566,8 @@ pub fn clickhouse_dialect() -> Dialect {
                 ]),
                 // This case CROSS JOIN
                 Ref::keyword("CROSS"),
+                // This case PASTE JOIN
+                Ref::keyword("PASTE"),
                 // This case ANY JOIN
                 Ref::keyword("ANY"),
                 // This case ALL JOIN
@@ -270,7 +615,14 @@ pub fn clickhouse_dialect() -> Dialect {
         Sequence::new(vec_of_erased![
             AnyNumberOf::new(vec_of_erased![Ref::new("ArrayJoinClauseSegment")])
                 .config(|this| this.min_times(1)),
-            Ref::new("AliasExpressionSegment").optional(),
+            Ref::new("AliasExpressionSegment")
+                .exclude(one_of(vec_of_erased![
+                    Ref::new("FromClauseTerminatorGrammar"),
+                    Ref::new("SamplingExpressionSegment"),
+                    Ref::keyword("FINAL"),
+                    Ref::new("JoinClauseSegment"),
+                ]))
+                .optional(),
         ])
         .to_matchable()
         .into(),
@@ -737,6 +1089,17 @@ pub fn clickhouse_dialect() -> Dialect {
                 Ref::new("IfNotExistsGrammar").optional(),
                 Ref::new("TableReferenceSegment"),
                 Ref::new("OnClusterClauseSegment").optional(),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("REFRESH"),
+                    one_of(vec_of_erased![Ref::keyword("EVERY"), Ref::keyword("AFTER")]),
+                    AnyNumberOf::new(vec_of_erased![Sequence::new(vec_of_erased![
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("DatetimeUnitSegment"),
+                    ])])
+                    .config(|this| this.min_times(1)),
+                    Ref::keyword("APPEND").optional(),
+                ])
+                .config(|this| this.optional()),
                 one_of(vec_of_erased![
                     Sequence::new(vec_of_erased![
                         Ref::keyword("TO"),
@@ -863,6 +1226,161 @@ pub fn clickhouse_dialect() -> Dialect {
         ),
     ]);
 
+    clickhouse_dialect.replace_grammar(
+        "CreateUserStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("CREATE"),
+            Ref::keyword("USER"),
+            one_of(vec_of_erased![Ref::new("IfNotExistsGrammar"), Ref::new("OrReplaceGrammar")])
+                .config(|this| this.optional()),
+            Delimited::new(vec_of_erased![Ref::new("SingleIdentifierGrammar")]),
+            Ref::new("OnClusterClauseSegment").optional(),
+            one_of(vec_of_erased![
+                Sequence::new(vec_of_erased![Ref::keyword("NOT"), Ref::keyword("IDENTIFIED")]),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("IDENTIFIED"),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("WITH"),
+                        Ref::new("NakedIdentifierSegment"),
+                    ])
+                    .config(|this| this.optional()),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("BY"),
+                        Ref::new("QuotedLiteralSegment"),
+                    ])
+                    .config(|this| this.optional()),
+                ]),
+            ])
+            .config(|this| this.optional()),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("HOST"),
+                one_of(vec_of_erased![
+                    Ref::keyword("ANY"),
+                    Ref::keyword("NONE"),
+                    Delimited::new(vec_of_erased![one_of(vec_of_erased![
+                        Ref::keyword("LOCAL"),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![
+                                Ref::keyword("NAME"),
+                                Ref::keyword("REGEXP"),
+                                Ref::keyword("IP"),
+                                Ref::keyword("LIKE"),
+                            ]),
+                            Ref::new("QuotedLiteralSegment"),
+                        ]),
+                    ])]),
+                ]),
+            ])
+            .config(|this| this.optional()),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("DEFAULT"),
+                Ref::keyword("ROLE"),
+                Ref::new("RoleListGrammar"),
+            ])
+            .config(|this| this.optional()),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("DEFAULT"),
+                Ref::keyword("DATABASE"),
+                one_of(vec_of_erased![Ref::keyword("NONE"), Ref::new("DatabaseReferenceSegment"),]),
+            ])
+            .config(|this| this.optional()),
+        ])
+        .to_matchable(),
+    );
+
+    clickhouse_dialect.replace_grammar(
+        "CreateRoleStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("CREATE"),
+            Ref::keyword("ROLE"),
+            one_of(vec_of_erased![Ref::new("IfNotExistsGrammar"), Ref::new("OrReplaceGrammar")])
+                .config(|this| this.optional()),
+            Delimited::new(vec_of_erased![Ref::new("SingleIdentifierGrammar")]),
+            Ref::new("OnClusterClauseSegment").optional(),
+        ])
+        .to_matchable(),
+    );
+
+    clickhouse_dialect.add([
+        (
+            // A list of roles, as accepted by `DEFAULT ROLE` and `TO`.
+            "RoleListGrammar".into(),
+            one_of(vec_of_erased![
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("ALL"),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("EXCEPT"),
+                        Delimited::new(vec_of_erased![Ref::new("SingleIdentifierGrammar")]),
+                    ])
+                    .config(|this| this.optional()),
+                ]),
+                Delimited::new(vec_of_erased![Ref::new("SingleIdentifierGrammar")]),
+            ])
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "CreateQuotaStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::CreateQuotaStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("CREATE"),
+                    Ref::keyword("QUOTA"),
+                    one_of(vec_of_erased![
+                        Ref::new("IfNotExistsGrammar"),
+                        Ref::new("OrReplaceGrammar")
+                    ])
+                    .config(|this| this.optional()),
+                    Delimited::new(vec_of_erased![Ref::new("SingleIdentifierGrammar")]),
+                    Ref::new("OnClusterClauseSegment").optional(),
+                    one_of(vec_of_erased![
+                        Sequence::new(vec_of_erased![
+                            Ref::keyword("KEYED"),
+                            Ref::keyword("BY"),
+                            Delimited::new(vec_of_erased![Ref::new("NakedIdentifierSegment")]),
+                        ]),
+                        Sequence::new(vec_of_erased![Ref::keyword("NOT"), Ref::keyword("KEYED")]),
+                    ])
+                    .config(|this| this.optional()),
+                    Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
+                        Ref::keyword("FOR"),
+                        Ref::keyword("RANDOMIZED").optional(),
+                        Ref::keyword("INTERVAL"),
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::new("DatetimeUnitSegment"),
+                        one_of(vec_of_erased![
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("MAX"),
+                                Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
+                                    Ref::new("NakedIdentifierSegment"),
+                                    Ref::new("EqualsSegment"),
+                                    one_of(vec_of_erased![
+                                        Ref::new("NumericLiteralSegment"),
+                                        Ref::new("QuotedLiteralSegment"),
+                                    ]),
+                                ])]),
+                            ]),
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("NO"),
+                                Ref::keyword("LIMITS")
+                            ]),
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("TRACKING"),
+                                Ref::keyword("ONLY")
+                            ]),
+                        ]),
+                    ])])
+                    .config(|this| this.optional()),
+                    Sequence::new(vec_of_erased![Ref::keyword("TO"), Ref::new("RoleListGrammar")])
+                        .config(|this| this.optional()),
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+    ]);
+
     clickhouse_dialect.replace_grammar(
         "DropViewStatementSegment",
         Sequence::new(vec_of_erased![
@@ -1153,15 +1671,120 @@ pub fn clickhouse_dialect() -> Dialect {
         ),
     ]);
 
+    clickhouse_dialect.add([(
+        "ShowStatementSegment".into(),
+        NodeMatcher::new(
+            SyntaxKind::ShowStatement,
+            Sequence::new(vec_of_erased![
+                Ref::keyword("SHOW"),
+                one_of(vec_of_erased![
+                    // SHOW CREATE [TEMPORARY] TABLE|DICTIONARY|VIEW|DATABASE name
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("CREATE"),
+                        one_of(vec_of_erased![
+                            Sequence::new(vec_of_erased![
+                                Ref::keyword("TEMPORARY").optional(),
+                                Ref::keyword("TABLE"),
+                            ]),
+                            Ref::keyword("DICTIONARY"),
+                            Ref::keyword("VIEW"),
+                        ])
+                        .config(|this| this.optional()),
+                        Ref::new("TableReferenceSegment"),
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("CREATE"),
+                        Ref::keyword("DATABASE"),
+                        Ref::new("DatabaseReferenceSegment"),
+                    ]),
+                    // SHOW DATABASES [LIKE ...] [LIMIT n]
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("DATABASES"),
+                        Ref::new("ShowLikeGrammar").optional(),
+                        Ref::new("LimitClauseSegment").optional(),
+                    ]),
+                    // SHOW [FULL] [TEMPORARY] TABLES|DICTIONARIES [FROM|IN db] [LIKE ...]
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("FULL").optional(),
+                        Ref::keyword("TEMPORARY").optional(),
+                        one_of(vec_of_erased![
+                            Ref::keyword("TABLES"),
+                            Ref::keyword("DICTIONARIES")
+                        ]),
+                        Sequence::new(vec_of_erased![
+                            one_of(vec_of_erased![Ref::keyword("FROM"), Ref::keyword("IN")]),
+                            Ref::new("DatabaseReferenceSegment"),
+                        ])
+                        .config(|this| this.optional()),
+                        Ref::new("ShowLikeGrammar").optional(),
+                        Ref::new("LimitClauseSegment").optional(),
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("FULL").optional(),
+                        Ref::keyword("PROCESSLIST"),
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("SETTINGS"),
+                        Ref::new("ShowLikeGrammar"),
+                    ]),
+                ]),
+            ])
+            .to_matchable(),
+        )
+        .to_matchable()
+        .into(),
+    )]);
+
+    clickhouse_dialect.add([(
+        "ShowLikeGrammar".into(),
+        Sequence::new(vec_of_erased![
+            Ref::keyword("NOT").optional(),
+            one_of(vec_of_erased![Ref::keyword("LIKE"), Ref::keyword("ILIKE")]),
+            Ref::new("QuotedLiteralSegment"),
+        ])
+        .to_matchable()
+        .into(),
+    )]);
+
+    clickhouse_dialect.replace_grammar(
+        "ExplainStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("EXPLAIN"),
+            one_of(vec_of_erased![
+                Ref::keyword("AST"),
+                Ref::keyword("SYNTAX"),
+                Sequence::new(vec_of_erased![Ref::keyword("QUERY"), Ref::keyword("TREE")]),
+                Ref::keyword("PLAN"),
+                Ref::keyword("PIPELINE"),
+                Ref::keyword("ESTIMATE"),
+            ])
+            .config(|this| this.optional()),
+            Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
+                Ref::new("NakedIdentifierSegment"),
+                Ref::new("EqualsSegment"),
+                one_of(vec_of_erased![
+                    Ref::new("NumericLiteralSegment"),
+                    Ref::new("QuotedLiteralSegment"),
+                    Ref::new("BooleanLiteralGrammar"),
+                ]),
+            ])])
+            .config(|this| this.optional()),
+            ansi::explainable_stmt(),
+        ])
+        .to_matchable(),
+    );
+
     clickhouse_dialect.replace_grammar(
         "StatementSegment",
         ansi::statement_segment().copy(
             Some(vec_of_erased![
                 Ref::new("CreateMaterializedViewStatementSegment"),
+                Ref::new("CreateQuotaStatementSegment"),
                 Ref::new("DropDictionaryStatementSegment"),
                 Ref::new("DropQuotaStatementSegment"),
                 Ref::new("DropSettingProfileStatementSegment"),
                 Ref::new("SystemStatementSegment"),
+                Ref::new("ShowStatementSegment"),
             ]),
             None,
             None,
@@ -1184,6 +1807,7 @@ mod tests {
     use crate::core::config::{FluffConfig, Value};
     use crate::core::linter::linter::Linter;
     use crate::core::parser::segments::base::ErasedSegment;
+    use crate::dialects::ansi_keywords::{ANSI_RESERVED_KEYWORDS, ANSI_UNRESERVED_KEYWORDS};
     use crate::helpers;
 
     fn parse_sql(linter: &Linter, sql: &str) -> ErasedSegment {
@@ -1202,7 +1826,8 @@ mod tests {
                 .into(),
                 None,
                 None,
-            ),
+            )
+            .unwrap(),
             None,
             None,
         );
@@ -1227,4 +1852,16 @@ mod tests {
             expect_file![yaml].assert_eq(&actual);
         });
     }
+
+    #[test]
+    fn keywords_are_valid() {
+        helpers::assert_keywords_valid(
+            &super::clickhouse_dialect(),
+            &[
+                &ANSI_RESERVED_KEYWORDS.lines().map(str::trim).collect_vec(),
+                &ANSI_UNRESERVED_KEYWORDS.lines().map(str::trim).collect_vec(),
+                super::UNRESERVED_KEYWORDS,
+            ],
+        );
+    }
 }
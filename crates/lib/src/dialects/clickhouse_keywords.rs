@@ -7,6 +7,7 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "AND",
     "ANTI",
     "ANY",
+    "APPEND",
     "ARRAY",
     "AS",
     "ASCENDING",
@@ -40,6 +41,7 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "DESCENDING",
     "DESCRIBE",
     "DETACH",
+    "DETACHED",
     "DICTIONARIES",
     "DICTIONARY",
     "DISK",
@@ -50,7 +52,9 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "END",
     "ENGINE",
     "EPHEMERAL",
+    "ESTIMATE",
     "EVENTS",
+    "EVERY",
     "EXCEPT",
     "EXISTS",
     "EXPLAIN",
@@ -82,9 +86,11 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "INJECTIVE",
     "INSERT",
     "INTO",
+    "IP",
     "IS",
     "IS_OBJECT_ID",
     "KEY",
+    "KEYED",
     "KILL",
     "LAST",
     "LAYOUT",
@@ -93,6 +99,7 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "LIFETIME",
     "LIKE",
     "LIMIT",
+    "LIMITS",
     "LIVE",
     "LOCAL",
     "LOGS",
@@ -114,7 +121,6 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "MYSQL",
     "NAN_SQL",
     "NO",
-    "NOT",
     "NULLS",
     "NULL_SQL",
     "OFFSET",
@@ -122,17 +128,24 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "OR",
     "OUTFILE",
     "OVERRIDE",
+    "PART",
     "PASTE",
+    "PIPELINE",
+    "PLAN",
     "POPULATE",
     "POSTGRESQL",
     "PREWHERE",
     "PRIMARY",
+    "PROCESSLIST",
     "PROFILE",
     "PROJECTION",
     "QUARTER",
+    "QUERY",
     "QUOTA",
     "QUEUES",
+    "RANDOMIZED",
     "RANGE",
+    "REFRESH",
     "RELOAD",
     "REMOVE",
     "RENAME",
@@ -167,7 +180,9 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "TO",
     "TOP",
     "TOTALS",
+    "TRACKING",
     "TRAILING",
+    "TREE",
     "TRIM",
     "TRUNCATE",
     "TTL",
@@ -239,7 +239,8 @@ mod tests {
                 .into(),
                 None,
                 None,
-            ),
+            )
+            .unwrap(),
             None,
             None,
         );
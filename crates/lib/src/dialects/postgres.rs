@@ -2107,58 +2107,39 @@ pub fn raw_dialect() -> Dialect {
         .to_matchable(),
     );
 
-    postgres.add([
-        (
-            "WithinGroupClauseSegment".into(),
-            NodeMatcher::new(
-                SyntaxKind::WithingroupClause,
-                Sequence::new(vec_of_erased![
-                    Ref::keyword("WITHIN"),
-                    Ref::keyword("GROUP"),
-                    Bracketed::new(vec_of_erased![Ref::new("OrderByClauseSegment").optional()]),
-                ])
-                .to_matchable(),
-            )
-            .to_matchable()
-            .into(),
-        ),
-        (
-            "GroupByClauseSegment".into(),
-            NodeMatcher::new(
-                SyntaxKind::GroupbyClause,
-                Sequence::new(vec_of_erased![
-                    Ref::keyword("GROUP"),
-                    Ref::keyword("BY"),
-                    MetaSegment::indent(),
-                    Delimited::new(vec_of_erased![one_of(vec_of_erased![
-                        Ref::new("ColumnReferenceSegment"),
-                        Ref::new("NumericLiteralSegment"),
-                        Ref::new("CubeRollupClauseSegment"),
-                        Ref::new("GroupingSetsClauseSegment"),
-                        Ref::new("ExpressionSegment"),
-                        Bracketed::new(vec_of_erased![]),
-                    ])])
-                    .config(|this| {
-                        this.terminators = vec_of_erased![
-                            Sequence::new(vec_of_erased![
-                                Ref::keyword("ORDER"),
-                                Ref::keyword("BY")
-                            ]),
-                            Ref::keyword("LIMIT"),
-                            Ref::keyword("HAVING"),
-                            Ref::keyword("QUALIFY"),
-                            Ref::keyword("WINDOW"),
-                            Ref::new("SetOperatorSegment"),
-                        ];
-                    }),
-                    MetaSegment::dedent(),
-                ])
-                .to_matchable(),
-            )
-            .to_matchable()
-            .into(),
-        ),
-    ]);
+    postgres.add([(
+        "GroupByClauseSegment".into(),
+        NodeMatcher::new(
+            SyntaxKind::GroupbyClause,
+            Sequence::new(vec_of_erased![
+                Ref::keyword("GROUP"),
+                Ref::keyword("BY"),
+                MetaSegment::indent(),
+                Delimited::new(vec_of_erased![one_of(vec_of_erased![
+                    Ref::new("ColumnReferenceSegment"),
+                    Ref::new("NumericLiteralSegment"),
+                    Ref::new("CubeRollupClauseSegment"),
+                    Ref::new("GroupingSetsClauseSegment"),
+                    Ref::new("ExpressionSegment"),
+                    Bracketed::new(vec_of_erased![]),
+                ])])
+                .config(|this| {
+                    this.terminators = vec_of_erased![
+                        Sequence::new(vec_of_erased![Ref::keyword("ORDER"), Ref::keyword("BY")]),
+                        Ref::keyword("LIMIT"),
+                        Ref::keyword("HAVING"),
+                        Ref::keyword("QUALIFY"),
+                        Ref::keyword("WINDOW"),
+                        Ref::new("SetOperatorSegment"),
+                    ];
+                }),
+                MetaSegment::dedent(),
+            ])
+            .to_matchable(),
+        )
+        .to_matchable()
+        .into(),
+    )]);
 
     postgres.replace_grammar(
         "CreateRoleStatementSegment",
@@ -6820,7 +6801,8 @@ mod tests {
                 .into(),
                 None,
                 None,
-            ),
+            )
+            .unwrap(),
             None,
             None,
         );
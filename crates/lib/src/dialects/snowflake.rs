@@ -1534,6 +1534,8 @@ pub fn snowflake_dialect() -> Dialect {
             .into(),
         ),
         ("TrimParametersGrammar".into(), Nothing::new().to_matchable().into()),
+        // OFFSET ... FETCH is part of the snowflake LimitClauseSegment.
+        ("OffsetClauseSegment".into(), Nothing::new().to_matchable().into()),
         (
             "GroupByClauseTerminatorGrammar".into(),
             one_of(vec_of_erased![
@@ -8299,7 +8301,8 @@ mod tests {
                 .into(),
                 None,
                 None,
-            ),
+            )
+            .unwrap(),
             None,
             None,
         );
@@ -50,7 +50,7 @@ pub fn sparksql_dialect() -> Dialect {
                 CodeSegmentNewArgs { code_type: SyntaxKind::BackQuote, ..<_>::default() },
             )
         }),
-        Matcher::regex("numeric_literal", r#"(?>(?>\d+\.\d+|\d+\.|\.\d+)([eE][+-]?\d+)?([dDfF]|BD|bd)?|\d+[eE][+-]?\d+([dDfF]|BD|bd)?|\d+([dDfFlLsSyY]|BD|bd)?)((?<=\.)|(?=\b))"#, |slice, marker| {
+        Matcher::regex("numeric_literal", r#"(?>0[xX][0-9a-fA-F]+|(?>\d+\.\d+|\d+\.(?!\.)|\.\d+)([eE][+-]?\d+)?([dDfF]|BD|bd)?|\d+[eE][+-]?\d+([dDfF]|BD|bd)?|\d+([dDfFlLsSyY]|BD|bd)?)((?<=\.)|(?=\b))"#, |slice, marker| {
             CodeSegment::create(
                 slice,
                 marker.into(),
@@ -111,6 +111,17 @@ pub fn sparksql_dialect() -> Dialect {
         "single_quote",
     );
 
+    sparksql_dialect.insert_lexer_matchers(
+        vec![Matcher::string("right_arrow", "=>", |slice, marker| {
+            CodeSegment::create(
+                slice,
+                marker.into(),
+                CodeSegmentNewArgs { code_type: SyntaxKind::RightArrow, ..Default::default() },
+            )
+        })],
+        "equals",
+    );
+
     sparksql_dialect.insert_lexer_matchers(
         vec![Matcher::regex("at_sign_literal", r"@\w*", |slice, marker| {
             CodeSegment::create(
@@ -171,7 +182,12 @@ pub fn sparksql_dialect() -> Dialect {
         "MICROSECONDS",
     ]);
 
-    sparksql_dialect.sets_mut("unreserved_keywords").extend(UNRESERVED_KEYWORDS);
+    // Spark reserves some keywords that ANSI leaves unreserved.
+    let unreserved_keywords = sparksql_dialect.sets_mut("unreserved_keywords");
+    for keyword in RESERVED_KEYWORDS {
+        unreserved_keywords.remove(keyword);
+    }
+    unreserved_keywords.extend(UNRESERVED_KEYWORDS);
     sparksql_dialect.sets_mut("reserved_keywords").extend(RESERVED_KEYWORDS);
 
     sparksql_dialect.update_bracket_sets(
@@ -492,7 +508,7 @@ pub fn sparksql_dialect() -> Dialect {
         (
             "NakedSemiStructuredElementSegment".into(),
             RegexParser::new(
-                "[A-Z0-9_]*",
+                "[A-Z0-9_]+",
                 |segment: &dyn Segment| {
                     CodeSegment::create(
                         &segment.raw(),
@@ -529,6 +545,24 @@ pub fn sparksql_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "RightArrowSegment".into(),
+            StringParser::new(
+                "=>",
+                |segment: &dyn Segment| {
+                    SymbolSegment::create(
+                        &segment.raw(),
+                        segment.get_position_marker(),
+                        SymbolSegmentNewArgs { r#type: SyntaxKind::RightArrow },
+                    )
+                },
+                None,
+                false,
+                None,
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "RightArrowOperator".into(),
             StringParser::new(
@@ -1248,6 +1282,23 @@ pub fn sparksql_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "MergeIntoLiteralGrammar".into(),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("MERGE"),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("WITH"),
+                    Ref::keyword("SCHEMA"),
+                    Ref::keyword("EVOLUTION")
+                ])
+                .config(|config| {
+                    config.optional();
+                }),
+                Ref::keyword("INTO")
+            ])
+            .to_matchable()
+            .into(),
+        ),
         (
             "WidgetDefaultGrammar".into(),
             Sequence::new(vec_of_erased![
@@ -1284,6 +1335,13 @@ pub fn sparksql_dialect() -> Dialect {
                             Ref::new("CommentGrammar").optional()
                         ]
                     )])]),
+                    // Column names with optional comments, naming the outputs of `AS SELECT`
+                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Sequence::new(
+                        vec_of_erased![
+                            Ref::new("ColumnReferenceSegment"),
+                            Ref::new("CommentGrammar").optional()
+                        ]
+                    )])]),
                     Sequence::new(vec_of_erased![
                         Ref::keyword("LIKE"),
                         one_of(vec_of_erased![
@@ -1302,7 +1360,10 @@ pub fn sparksql_dialect() -> Dialect {
                     Ref::new("CommentGrammar"),
                     Ref::new("OptionsGrammar"),
                     Ref::new("PartitionSpecGrammar"),
-                    Ref::new("BucketSpecGrammar")
+                    Ref::new("BucketSpecGrammar"),
+                    // Hive format tables may give these before the storage clauses.
+                    Ref::new("LocationGrammar"),
+                    Ref::new("TablePropertiesGrammar")
                 ])
                 .config(|config| {
                     config.optional();
@@ -1442,6 +1503,8 @@ pub fn sparksql_dialect() -> Dialect {
                     Ref::keyword("DOUBLE"),
                     Ref::keyword("DATE"),
                     Ref::keyword("TIMESTAMP"),
+                    Ref::keyword("TIMESTAMP_LTZ"),
+                    Ref::keyword("TIMESTAMP_NTZ"),
                     Ref::keyword("STRING"),
                     Sequence::new(vec_of_erased![
                         one_of(vec_of_erased![
@@ -1455,7 +1518,9 @@ pub fn sparksql_dialect() -> Dialect {
                         Ref::new("BracketedArguments").optional()
                     ]),
                     Ref::keyword("BINARY"),
-                    Ref::keyword("INTERVAL")
+                    Ref::keyword("INTERVAL"),
+                    Ref::keyword("VARIANT"),
+                    Ref::keyword("VOID")
                 ])
                 .to_matchable(),
             )
@@ -1581,6 +1646,7 @@ pub fn sparksql_dialect() -> Dialect {
                 Sequence::new(vec_of_erased![
                     Ref::keyword("ADD"),
                     one_of(vec_of_erased![Ref::keyword("COLUMNS"), Ref::keyword("COLUMN")]),
+                    Ref::new("IfNotExistsGrammar").optional(),
                     MetaSegment::indent(),
                     optionally_bracketed(vec_of_erased![Delimited::new(vec_of_erased![
                         Sequence::new(vec_of_erased![
@@ -1945,9 +2011,12 @@ pub fn sparksql_dialect() -> Dialect {
             Ref::new("IfNotExistsGrammar").optional(),
             Ref::new("TableReferenceSegment"),
             Sequence::new(vec_of_erased![Bracketed::new(vec_of_erased![Delimited::new(
-                vec_of_erased![Sequence::new(vec_of_erased![
-                    Ref::new("ColumnReferenceSegment"),
-                    Ref::new("CommentGrammar").optional()
+                vec_of_erased![one_of(vec_of_erased![
+                    Sequence::new(vec_of_erased![
+                        Ref::new("ColumnReferenceSegment"),
+                        Ref::new("CommentGrammar").optional()
+                    ]),
+                    Ref::new("ColumnDefinitionSegment")
                 ])]
             )])])
             .config(|config| {
@@ -2031,6 +2100,40 @@ pub fn sparksql_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "ExecuteImmediateStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ExecuteImmediateStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("EXECUTE"),
+                    Ref::keyword("IMMEDIATE"),
+                    one_of(vec_of_erased![
+                        Ref::new("QuotedLiteralSegment"),
+                        Ref::new("SingleIdentifierGrammar")
+                    ]),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("INTO"),
+                        Delimited::new(vec_of_erased![Ref::new("SingleIdentifierGrammar")])
+                    ])
+                    .config(|config| {
+                        config.optional();
+                    }),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("USING"),
+                        Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
+                            Ref::new("BaseExpressionElementGrammar"),
+                            Ref::new("AliasExpressionSegment").optional()
+                        ])])
+                    ])
+                    .config(|config| {
+                        config.optional();
+                    })
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
     ]);
 
     sparksql_dialect.replace_grammar(
@@ -2099,7 +2202,11 @@ pub fn sparksql_dialect() -> Dialect {
                     one_of(vec_of_erased![Ref::keyword("INTO"), Ref::keyword("OVERWRITE")]),
                     Ref::keyword("TABLE").optional(),
                     Ref::new("TableReferenceSegment"),
-                    Ref::new("PartitionSpecGrammar").optional(),
+                    Sequence::new(vec_of_erased![
+                        Ref::new("PartitionSpecGrammar"),
+                        Ref::new("IfNotExistsGrammar").optional()
+                    ])
+                    .config(|this| this.optional()),
                     Ref::new("BracketedColumnReferenceListGrammar").optional(),
                     one_of(vec_of_erased![
                         AnyNumberOf::new(vec_of_erased![Ref::new("ValuesClauseSegment")]).config(
@@ -2353,13 +2460,21 @@ pub fn sparksql_dialect() -> Dialect {
 
     sparksql_dialect.replace_grammar(
         "SelectClauseModifierSegment",
-        Sequence::new(vec_of_erased![
-            Ref::new("SelectHintSegment").optional(),
-            one_of(vec_of_erased![Ref::keyword("DISTINCT"), Ref::keyword("ALL")]).config(
-                |config| {
-                    config.optional();
-                }
-            )
+        one_of(vec_of_erased![
+            Sequence::new(vec_of_erased![
+                Ref::new("SelectHintSegment"),
+                one_of(vec_of_erased![Ref::keyword("DISTINCT"), Ref::keyword("ALL")]).config(
+                    |config| {
+                        config.optional();
+                    }
+                )
+            ]),
+            // Spark requires the hint first, but the reverse order is parsed so that it can be
+            // reported by `layout.select_modifier_order` rather than left unparsable.
+            Sequence::new(vec_of_erased![
+                one_of(vec_of_erased![Ref::keyword("DISTINCT"), Ref::keyword("ALL")]),
+                Ref::new("SelectHintSegment").optional()
+            ])
         ])
         .to_matchable(),
     );
@@ -2409,6 +2524,7 @@ pub fn sparksql_dialect() -> Dialect {
             MetaSegment::indent(),
             one_of(vec_of_erased![
                 Delimited::new(vec_of_erased![
+                    Ref::keyword("ALL"),
                     Ref::new("ColumnReferenceSegment"),
                     Ref::new("NumericLiteralSegment"),
                     Ref::new("CubeRollupClauseSegment"),
@@ -2431,6 +2547,42 @@ pub fn sparksql_dialect() -> Dialect {
         ])
         .to_matchable(),
     );
+
+    sparksql_dialect.replace_grammar(
+        "OrderByClauseSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("ORDER"),
+            Ref::keyword("BY"),
+            MetaSegment::indent(),
+            Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
+                one_of(vec_of_erased![
+                    Ref::keyword("ALL"),
+                    Ref::new("ColumnReferenceSegment"),
+                    Ref::new("NumericLiteralSegment"),
+                    Ref::new("ExpressionSegment")
+                ]),
+                one_of(vec_of_erased![Ref::keyword("ASC"), Ref::keyword("DESC")]).config(
+                    |config| {
+                        config.optional();
+                    }
+                ),
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("NULLS"),
+                    one_of(vec_of_erased![Ref::keyword("FIRST"), Ref::keyword("LAST")])
+                ])
+                .config(|config| {
+                    config.optional();
+                })
+            ])])
+            .config(|config| {
+                config.terminators =
+                    vec_of_erased![Ref::keyword("LIMIT"), Ref::new("FrameClauseUnitGrammar")];
+            }),
+            MetaSegment::dedent()
+        ])
+        .to_matchable(),
+    );
+
     sparksql_dialect.add([
         (
             "WithCubeRollupClauseSegment".into(),
@@ -2593,9 +2745,13 @@ pub fn sparksql_dialect() -> Dialect {
                 SyntaxKind::TransformClause,
                 Sequence::new(vec_of_erased![
                     Ref::keyword("TRANSFORM"),
-                    Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![Ref::new(
-                        "SingleIdentifierGrammar"
-                    )])])
+                    Bracketed::new(vec_of_erased![
+                        one_of(vec_of_erased![Ref::keyword("DISTINCT"), Ref::keyword("ALL")])
+                            .config(|config| {
+                                config.optional();
+                            }),
+                        Delimited::new(vec_of_erased![Ref::new("BaseExpressionElementGrammar")])
+                    ])
                     .config(|config| {
                         config.parse_mode(ParseMode::Greedy);
                     }),
@@ -2605,12 +2761,19 @@ pub fn sparksql_dialect() -> Dialect {
                     Ref::new("QuotedLiteralSegment"),
                     Sequence::new(vec_of_erased![
                         Ref::keyword("AS"),
-                        Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![
-                            AnyNumberOf::new(vec_of_erased![
+                        // The output schema may be given with or without brackets.
+                        one_of(vec_of_erased![
+                            Bracketed::new(vec_of_erased![Delimited::new(vec_of_erased![
+                                Sequence::new(vec_of_erased![
+                                    Ref::new("SingleIdentifierGrammar"),
+                                    Ref::new("DatatypeSegment").optional()
+                                ])
+                            ])]),
+                            Delimited::new(vec_of_erased![Sequence::new(vec_of_erased![
                                 Ref::new("SingleIdentifierGrammar"),
-                                Ref::new("DatatypeSegment")
-                            ])
-                        ])])
+                                Ref::new("DatatypeSegment").optional()
+                            ])])
+                        ])
                     ])
                     .config(|config| {
                         config.optional();
@@ -2746,6 +2909,98 @@ pub fn sparksql_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "CallStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::CallStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("CALL"),
+                    Ref::new("ObjectReferenceSegment"),
+                    Bracketed::new(vec_of_erased![
+                        Delimited::new(vec_of_erased![one_of(vec_of_erased![
+                            Ref::new("NamedArgumentSegment"),
+                            Ref::new("ExpressionSegment")
+                        ])])
+                        .config(|config| {
+                            config.optional();
+                        })
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // `IDENTIFIER(...)` is an identifier clause rather than a function call.
+            "FunctionNameIdentifierSegment".into(),
+            RegexParser::new(
+                "[A-Z0-9_]+",
+                |segment| {
+                    CodeSegment::create(
+                        &segment.raw(),
+                        segment.get_position_marker(),
+                        CodeSegmentNewArgs {
+                            code_type: SyntaxKind::FunctionNameIdentifier,
+                            ..Default::default()
+                        },
+                    )
+                },
+                None,
+                false,
+                "^IDENTIFIER$".to_owned().into(),
+                None,
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "IdentifierClauseSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::IdentifierClause,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("IDENTIFIER"),
+                    Bracketed::new(vec_of_erased![one_of(vec_of_erased![
+                        Ref::new("ParameterizedSegment"),
+                        Ref::new("ExpressionSegment")
+                    ])])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            // A named parameter marker, e.g. `:table_name`.
+            "ParameterizedSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ParameterizedExpression,
+                Sequence::new(vec_of_erased![
+                    Ref::new("ColonSegment"),
+                    Ref::new("NakedIdentifierSegment")
+                ])
+                .config(|config| {
+                    config.disallow_gaps();
+                })
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "NamedArgumentSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::NamedArgument,
+                Sequence::new(vec_of_erased![
+                    Ref::new("ParameterNameSegment"),
+                    Ref::new("RightArrowSegment"),
+                    Ref::new("ExpressionSegment")
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "ClearCacheSegment".into(),
             NodeMatcher::new(
@@ -3096,6 +3351,7 @@ pub fn sparksql_dialect() -> Dialect {
                 Ref::new("AddJarSegment"),
                 Ref::new("AnalyzeTableSegment"),
                 Ref::new("CacheTableSegment"),
+                Ref::new("CallStatementSegment"),
                 Ref::new("ClearCacheSegment"),
                 Ref::new("ListFileSegment"),
                 Ref::new("ListJarSegment"),
@@ -3117,9 +3373,11 @@ pub fn sparksql_dialect() -> Dialect {
                 Ref::new("RestoreTableStatementSegment"),
                 Ref::new("ConstraintStatementSegment"),
                 Ref::new("ApplyChangesIntoStatementSegment"),
+                Ref::new("CreateFlowStatementSegment"),
                 Ref::new("CreateWidgetStatementSegment"),
                 Ref::new("RemoveWidgetStatementSegment"),
                 Ref::new("ReplaceTableStatementSegment"),
+                Ref::new("ExecuteImmediateStatementSegment"),
             ]),
             None,
             None,
@@ -3238,6 +3496,27 @@ pub fn sparksql_dialect() -> Dialect {
         .to_matchable(),
     );
 
+    // `IDENTIFIER(...)` can stand in for any table or column name.
+    sparksql_dialect.replace_grammar(
+        "TableReferenceSegment",
+        one_of(vec_of_erased![
+            Ref::new("IdentifierClauseSegment"),
+            Ref::new("ObjectReferenceSegment")
+        ])
+        .to_matchable(),
+    );
+
+    sparksql_dialect.replace_grammar(
+        "ColumnReferenceSegment",
+        one_of(vec_of_erased![
+            Ref::new("IdentifierClauseSegment"),
+            Delimited::new(vec_of_erased![Ref::new("SingleIdentifierGrammar")]).config(|config| {
+                config.delimiter(Ref::new("ObjectReferenceDelimiterGrammar"));
+            })
+        ])
+        .to_matchable(),
+    );
+
     sparksql_dialect.replace_grammar(
         "TableExpressionSegment",
         one_of(vec_of_erased![
@@ -3408,7 +3687,7 @@ pub fn sparksql_dialect() -> Dialect {
                 }
             ),
             Ref::new("SetClauseListSegment"),
-            Ref::new("WhereClauseSegment")
+            Ref::new("WhereClauseSegment").optional()
         ])
         .to_matchable(),
     );
@@ -3610,6 +3889,26 @@ pub fn sparksql_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
+        (
+            "CreateFlowStatementSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::CreateFlowStatement,
+                Sequence::new(vec_of_erased![
+                    Ref::keyword("CREATE"),
+                    Ref::keyword("FLOW"),
+                    Ref::new("ObjectReferenceSegment"),
+                    Ref::new("CommentGrammar").optional(),
+                    Ref::keyword("AS"),
+                    one_of(vec_of_erased![
+                        Ref::new("InsertStatementSegment"),
+                        Ref::new("ApplyChangesIntoStatementSegment")
+                    ])
+                ])
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
         (
             "ApplyChangesIntoStatementSegment".into(),
             NodeMatcher::new(
@@ -3821,6 +4120,17 @@ pub fn sparksql_dialect() -> Dialect {
                 ])
             ]);
 
+            let frame_exclusion = Sequence::new(vec_of_erased![
+                Ref::keyword("EXCLUDE"),
+                one_of(vec_of_erased![
+                    Sequence::new(vec_of_erased![Ref::keyword("CURRENT"), Ref::keyword("ROW")]),
+                    Ref::keyword("GROUP"),
+                    Ref::keyword("TIES"),
+                    Sequence::new(vec_of_erased![Ref::keyword("NO"), Ref::keyword("OTHERS")])
+                ])
+            ])
+            .config(|this| this.optional());
+
             Sequence::new(vec_of_erased![
                 Ref::new("FrameClauseUnitGrammar"),
                 one_of(vec_of_erased![
@@ -3831,7 +4141,8 @@ pub fn sparksql_dialect() -> Dialect {
                         Ref::keyword("AND"),
                         frame_extent
                     ])
-                ])
+                ]),
+                frame_exclusion
             ])
         }
         .to_matchable(),
@@ -3850,6 +4161,7 @@ mod tests {
     use crate::core::config::{FluffConfig, Value};
     use crate::core::linter::linter::Linter;
     use crate::core::parser::segments::base::ErasedSegment;
+    use crate::dialects::ansi_keywords::{ANSI_RESERVED_KEYWORDS, ANSI_UNRESERVED_KEYWORDS};
     use crate::helpers;
 
     fn parse_sql(linter: &Linter, sql: &str) -> ErasedSegment {
@@ -3868,7 +4180,8 @@ mod tests {
                 .into(),
                 None,
                 None,
-            ),
+            )
+            .unwrap(),
             None,
             None,
         );
@@ -3893,4 +4206,27 @@ mod tests {
             expect_file![yaml].assert_eq(&actual);
         });
     }
+
+    #[test]
+    fn angle_bracket_pairs() {
+        let dialect = super::sparksql_dialect();
+
+        assert_eq!(
+            dialect.bracket_pairs("angle_bracket_pairs"),
+            [("angle", "StartAngleBracketSegment", "EndAngleBracketSegment", false)]
+        );
+    }
+
+    #[test]
+    fn keywords_are_valid() {
+        helpers::assert_keywords_valid(
+            &super::sparksql_dialect(),
+            &[
+                &ANSI_RESERVED_KEYWORDS.lines().map(str::trim).collect_vec(),
+                &ANSI_UNRESERVED_KEYWORDS.lines().map(str::trim).collect_vec(),
+                super::RESERVED_KEYWORDS,
+                super::UNRESERVED_KEYWORDS,
+            ],
+        );
+    }
 }
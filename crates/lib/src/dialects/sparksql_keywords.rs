@@ -68,6 +68,9 @@ pub const RESERVED_KEYWORDS: &[&str] = &[
     "WITH",
 ];
 
+// `IGNORE`, `INTERVAL`, `PARTITION`, `RESPECT`, `ROWS` and `SET` are unreserved
+// in Spark, but are left out here so they stay reserved as in ANSI; the grammar
+// relies on them never being read as identifiers.
 pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "ADD",
     "AFTER",
@@ -84,6 +87,7 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "BUCKETS",
     "BY",
     "CACHE",
+    "CALL",
     "CASCADE",
     "CHANGE",
     "CLEAR",
@@ -123,7 +127,9 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "DIV",
     "DROP",
     "ESCAPED",
+    "EVOLUTION",
     "EXCHANGE",
+    "EXECUTE",
     "EXISTS",
     "EXPLAIN",
     "EXPORT",
@@ -145,15 +151,14 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "HOURS",
     "IDENTIFIER",
     "IF",
-    "IGNORE",
     "ILIKE",
+    "IMMEDIATE",
     "IMPORT",
     "INDEX",
     "INDEXES",
     "INPATH",
     "INPUTFORMAT",
     "INSERT",
-    "INTERVAL",
     "ITEMS",
     "KEYS",
     "LAST",
@@ -190,7 +195,6 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "OVER",
     "OVERLAY",
     "OVERWRITE",
-    "PARTITION",
     "PARTITIONED",
     "PARTITIONS",
     "PERCENTLIT",
@@ -214,7 +218,6 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "REPEATABLE",
     "REPLACE",
     "RESET",
-    "RESPECT",
     "RESTRICT",
     "REVOKE",
     "RLIKE",
@@ -223,14 +226,12 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "ROLLBACK",
     "ROLLUP",
     "ROW",
-    "ROWS",
     "SCHEMA",
     "SECOND",
     "SEMI",
     "SEPARATED",
     "SERDE",
     "SERDEPROPERTIES",
-    "SET",
     "SETMINUS",
     "SETS",
     "SHOW",
@@ -253,6 +254,8 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "TEMPORARY",
     "TERMINATED",
     "TIME",
+    "TIMESTAMP_LTZ",
+    "TIMESTAMP_NTZ",
     "TOUCH",
     "TRANSACTION",
     "TRANSACTIONS",
@@ -270,8 +273,10 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "UPDATE",
     "USE",
     "VALUES",
+    "VARIANT",
     "VIEW",
     "VIEWS",
+    "VOID",
     "WRITE",
     "WINDOW",
     "YEAR",
@@ -301,6 +306,7 @@ pub const UNRESERVED_KEYWORDS: &[&str] = &[
     "DELETES",
     "EXPECT",
     "FAIL",
+    "FLOW",
     "LIVE",
     "SCD",
     "STREAMING",
@@ -6,8 +6,11 @@ use crate::core::parser::grammar::anyof::{one_of, optionally_bracketed, AnyNumbe
 use crate::core::parser::grammar::base::{Anything, Nothing, Ref};
 use crate::core::parser::grammar::delimited::Delimited;
 use crate::core::parser::grammar::sequence::{Bracketed, Sequence};
+use crate::core::parser::lexer::Matcher;
 use crate::core::parser::parsers::TypedParser;
-use crate::core::parser::segments::base::{Segment, SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::parser::segments::base::{
+    CodeSegment, CodeSegmentNewArgs, Segment, SymbolSegment, SymbolSegmentNewArgs,
+};
 use crate::core::parser::segments::meta::MetaSegment;
 use crate::core::parser::types::ParseMode;
 use crate::dialects::ansi::NodeMatcher;
@@ -30,6 +33,17 @@ pub fn raw_dialect() -> Dialect {
     sqlite_dialect.sets_mut("unreserved_keywords").clear();
     sqlite_dialect.sets_mut("unreserved_keywords").extend(UNRESERVED_KEYWORDS);
 
+    sqlite_dialect.insert_lexer_matchers(
+        vec![Matcher::regex("bind_parameter", r"\?[0-9]*|[:@$][a-zA-Z_][\w]*", |slice, marker| {
+            CodeSegment::create(
+                slice,
+                marker.into(),
+                CodeSegmentNewArgs { code_type: SyntaxKind::Parameter, ..Default::default() },
+            )
+        })],
+        "equals",
+    );
+
     sqlite_dialect.add([
         (
             "BooleanBinaryOperatorGrammar".into(),
@@ -96,12 +110,23 @@ pub fn raw_dialect() -> Dialect {
         ("IntervalExpressionSegment".into(), Nothing::new().to_matchable().into()),
         ("TimeZoneGrammar".into(), Nothing::new().to_matchable().into()),
         ("FetchClauseSegment".into(), Nothing::new().to_matchable().into()),
+        ("OffsetClauseSegment".into(), Nothing::new().to_matchable().into()),
         ("TrimParametersGrammar".into(), Nothing::new().to_matchable().into()),
         (
             "LikeGrammar".into(),
             Sequence::new(vec_of_erased![Ref::keyword("LIKE")]).to_matchable().into(),
         ),
         ("OverlapsClauseSegment".into(), Nothing::new().to_matchable().into()),
+        (
+            "FrameClauseUnitGrammar".into(),
+            one_of(vec_of_erased![
+                Ref::keyword("ROWS"),
+                Ref::keyword("RANGE"),
+                Ref::keyword("GROUPS")
+            ])
+            .to_matchable()
+            .into(),
+        ),
         ("MLTableExpressionSegment".into(), Nothing::new().to_matchable().into()),
         ("MergeIntoLiteralGrammar".into(), Nothing::new().to_matchable().into()),
         ("SamplingExpressionSegment".into(), Nothing::new().to_matchable().into()),
@@ -152,7 +177,15 @@ pub fn raw_dialect() -> Dialect {
             .to_matchable()
             .into(),
         ),
-        ("PostFunctionGrammar".into(), Ref::new("FilterClauseGrammar").to_matchable().into()),
+        (
+            "PostFunctionGrammar".into(),
+            Sequence::new(vec_of_erased![
+                Ref::new("FilterClauseGrammar").optional(),
+                Ref::new("OverClauseSegment").optional()
+            ])
+            .to_matchable()
+            .into(),
+        ),
         ("IgnoreRespectNullsGrammar".into(), Nothing::new().to_matchable().into()),
         (
             "SelectClauseTerminatorGrammar".into(),
@@ -280,6 +313,36 @@ pub fn raw_dialect() -> Dialect {
         .into(),
     )]);
 
+    sqlite_dialect.replace_grammar(
+        "FrameClauseSegment",
+        {
+            let frame_extent = one_of(vec_of_erased![
+                Sequence::new(vec_of_erased![Ref::keyword("CURRENT"), Ref::keyword("ROW")]),
+                Sequence::new(vec_of_erased![
+                    one_of(vec_of_erased![
+                        Ref::new("NumericLiteralSegment"),
+                        Ref::keyword("UNBOUNDED")
+                    ]),
+                    one_of(vec_of_erased![Ref::keyword("PRECEDING"), Ref::keyword("FOLLOWING")])
+                ])
+            ]);
+
+            Sequence::new(vec_of_erased![
+                Ref::new("FrameClauseUnitGrammar"),
+                one_of(vec_of_erased![
+                    frame_extent.clone(),
+                    Sequence::new(vec_of_erased![
+                        Ref::keyword("BETWEEN"),
+                        frame_extent.clone(),
+                        Ref::keyword("AND"),
+                        frame_extent
+                    ])
+                ])
+            ])
+        }
+        .to_matchable(),
+    );
+
     sqlite_dialect.replace_grammar(
         "DatatypeSegment",
         one_of(vec_of_erased![
@@ -534,7 +597,12 @@ pub fn raw_dialect() -> Dialect {
             Sequence::new(vec_of_erased![
                 Ref::keyword("PRAGMA"),
                 Ref::new("PragmaReferenceSegment"),
-                Bracketed::new(vec_of_erased![pragma_value.clone()]).config(|config| {
+                // Pragmas such as `table_info(table-name)` take the name of an object.
+                Bracketed::new(vec_of_erased![one_of(vec_of_erased![
+                    pragma_value.clone(),
+                    Ref::new("SingleIdentifierGrammar")
+                ])])
+                .config(|config| {
                     config.optional();
                 }),
                 Sequence::new(vec_of_erased![
@@ -683,6 +751,75 @@ pub fn raw_dialect() -> Dialect {
         .to_matchable(),
     );
 
+    sqlite_dialect.add([
+        (
+            "ParameterizedSegment".into(),
+            NodeMatcher::new(
+                SyntaxKind::ParameterizedExpression,
+                TypedParser::new(
+                    SyntaxKind::Parameter,
+                    |segment: &dyn Segment| {
+                        CodeSegment::create(
+                            &segment.raw(),
+                            segment.get_position_marker(),
+                            CodeSegmentNewArgs {
+                                code_type: SyntaxKind::Parameter,
+                                ..Default::default()
+                            },
+                        )
+                    },
+                    None,
+                    false,
+                    None,
+                )
+                .to_matchable(),
+            )
+            .to_matchable()
+            .into(),
+        ),
+        (
+            "LiteralGrammar".into(),
+            sqlite_dialect
+                .grammar("LiteralGrammar")
+                .copy(
+                    Some(vec_of_erased![Ref::new("ParameterizedSegment")]),
+                    None,
+                    None,
+                    None,
+                    Vec::new(),
+                    false,
+                )
+                .into(),
+        ),
+    ]);
+
+    sqlite_dialect.replace_grammar(
+        "CTEDefinitionSegment",
+        Sequence::new(vec_of_erased![
+            Ref::new("SingleIdentifierGrammar"),
+            Ref::new("CTEColumnList").optional(),
+            Ref::keyword("AS").optional(),
+            Sequence::new(vec_of_erased![
+                Ref::keyword("NOT").optional(),
+                Ref::keyword("MATERIALIZED")
+            ])
+            .config(|this| this.optional()),
+            Bracketed::new(vec_of_erased![Ref::new("SelectableGrammar")])
+        ])
+        .to_matchable(),
+    );
+
+    sqlite_dialect.replace_grammar(
+        "ExplainStatementSegment",
+        Sequence::new(vec_of_erased![
+            Ref::keyword("EXPLAIN"),
+            Sequence::new(vec_of_erased![Ref::keyword("QUERY"), Ref::keyword("PLAN")])
+                .config(|this| this.optional()),
+            Ref::new("StatementSegment")
+        ])
+        .to_matchable(),
+    );
+
     sqlite_dialect.replace_grammar(
         "StatementSegment",
         one_of(vec_of_erased![
@@ -737,7 +874,8 @@ mod tests {
                 .into(),
                 None,
                 None,
-            ),
+            )
+            .unwrap(),
             None,
             None,
         );
@@ -762,4 +900,12 @@ mod tests {
             expect_file![yaml].assert_eq(&actual);
         });
     }
+
+    #[test]
+    fn keywords_are_valid() {
+        helpers::assert_keywords_valid(
+            &super::dialect(),
+            &[super::RESERVED_KEYWORDS, super::UNRESERVED_KEYWORDS],
+        );
+    }
 }
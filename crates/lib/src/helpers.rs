@@ -134,6 +134,37 @@ pub fn normalize(p: &Path) -> PathBuf {
     norm_path
 }
 
+/// Assert that a dialect's reserved and unreserved keyword sets are disjoint
+/// and that the keyword lists it was built from contain no duplicates.
+#[cfg(test)]
+#[track_caller]
+pub(crate) fn assert_keywords_valid(
+    dialect: &crate::core::dialects::base::Dialect,
+    keyword_lists: &[&[&str]],
+) {
+    use itertools::Itertools;
+
+    for list in keyword_lists {
+        let mut seen = ahash::AHashSet::new();
+        for keyword in list.iter() {
+            assert!(
+                seen.insert(keyword),
+                "Duplicate keyword `{keyword}` in the {} dialect.",
+                dialect.name()
+            );
+        }
+    }
+
+    let reserved = dialect.sets("reserved_keywords");
+    let unreserved = dialect.sets("unreserved_keywords");
+    if let Some(keyword) = reserved.intersection(&unreserved).sorted().next() {
+        panic!(
+            "Keyword `{keyword}` is both reserved and unreserved in the {} dialect.",
+            dialect.name()
+        );
+    }
+}
+
 pub fn enter_panic(context: String) -> PanicContext {
     static ONCE: Once = Once::new();
     ONCE.call_once(PanicContext::init);
@@ -8,6 +8,8 @@ pub mod ambiguous;
 pub mod capitalisation;
 pub mod convention;
 pub mod layout;
+pub mod performance;
+pub mod portability;
 pub mod references;
 pub mod structure;
 
@@ -18,6 +20,8 @@ pub fn rules() -> Vec<ErasedRule> {
         capitalisation::rules(),
         convention::rules(),
         layout::rules(),
+        performance::rules(),
+        portability::rules(),
         references::rules(),
         structure::rules()
     )
@@ -55,7 +59,7 @@ mod tests {
     #[test]
     fn no_rule_should_not_include_all_as_that_is_default() {
         rules().iter().for_each(|rule| {
-            assert_eq!(*rule.groups().get(0).unwrap(), RuleGroups::All);
+            assert_eq!(*rule.groups().first().unwrap(), RuleGroups::All);
         });
     }
 
@@ -71,7 +75,7 @@ mod tests {
     fn if_rule_contains_core_is_second_in_list() {
         rules().iter().for_each(|rule| {
             let groups = rule.groups();
-            if groups.into_iter().find(|&&rule| rule == RuleGroups::Core).is_some() {
+            if groups.contains(&RuleGroups::Core) {
                 assert_eq!(groups.get(1).unwrap(), &RuleGroups::Core);
             }
         })
@@ -86,6 +90,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_range_loop)]
     fn rule_skip_dialect_should_be_alphabetical() {
         rules().iter().for_each(|rule| {
             let skips = rule.dialect_skip();
@@ -7,6 +7,8 @@ pub mod am04;
 pub mod am05;
 pub mod am06;
 pub mod am07;
+pub mod am08;
+pub mod am09;
 
 pub fn rules() -> Vec<ErasedRule> {
     use crate::core::rules::base::Erased as _;
@@ -19,5 +21,7 @@ pub fn rules() -> Vec<ErasedRule> {
         am05::RuleAM05::default().erased(),
         am06::RuleAM06::default().erased(),
         am07::RuleAM07.erased(),
+        am08::RuleAM08.erased(),
+        am09::RuleAM09.erased(),
     ]
 }
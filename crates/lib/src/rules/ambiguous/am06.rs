@@ -1,7 +1,7 @@
 use ahash::AHashMap;
 
 use crate::core::config::Value;
-use crate::core::rules::base::{CloneRule, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::base::{CloneRule, ErasedRule, LintFix, LintResult, Rule, RuleGroups};
 use crate::core::rules::context::RuleContext;
 use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
 use crate::dialects::{SyntaxKind, SyntaxSet};
@@ -72,6 +72,8 @@ SELECT
 FROM foo
 ORDER BY a ASC, b DESC
 ```
+
+When `group_by_and_order_by_style` is `explicit`, ordinals which refer to a plain column in the select clause are fixed to that column.
 "#
     }
     fn groups(&self) -> &'static [RuleGroups] {
@@ -140,12 +142,23 @@ ORDER BY a ASC, b DESC
             .into_iter()
             .any(|category| *category != self.group_by_and_order_by_style)
         {
-            return vec![LintResult::new(context.segment.into(), Vec::new(), None, None, None)];
+            let fixes = if self.group_by_and_order_by_style == GroupByAndOrderByConvention::Explicit
+            {
+                ordinal_fixes(&context)
+            } else {
+                Vec::new()
+            };
+
+            return vec![LintResult::new(context.segment.into(), fixes, None, None, None)];
         }
 
         vec![]
     }
 
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
     fn crawl_behaviour(&self) -> Crawler {
         SegmentSeekerCrawler::new(
             const {
@@ -159,3 +172,66 @@ ORDER BY a ASC, b DESC
         .into()
     }
 }
+
+/// Replace each ordinal in the clause with the select target it refers to.
+///
+/// This is only done when every ordinal refers to a plain column reference in
+/// the select statement directly containing the clause, otherwise the
+/// replacement could change the meaning of the query.
+fn ordinal_fixes(context: &RuleContext) -> Vec<LintFix> {
+    let Some(select_clause) = context
+        .parent_stack
+        .last()
+        .filter(|parent| parent.is_type(SyntaxKind::SelectStatement))
+        .and_then(|parent| {
+            parent.segments().iter().find(|it| it.is_type(SyntaxKind::SelectClause)).cloned()
+        })
+    else {
+        return Vec::new();
+    };
+
+    let select_targets: Vec<_> = select_clause
+        .segments()
+        .iter()
+        .filter(|it| it.is_type(SyntaxKind::SelectClauseElement))
+        .cloned()
+        .collect();
+
+    // Ordinals can't be resolved without knowing the columns a wildcard expands to.
+    if select_targets
+        .iter()
+        .any(|target| target.segments().iter().any(|it| it.is_type(SyntaxKind::WildcardExpression)))
+    {
+        return Vec::new();
+    }
+
+    let mut fixes = Vec::new();
+    for ordinal in
+        context.segment.segments().iter().filter(|it| it.is_type(SyntaxKind::NumericLiteral))
+    {
+        let column_reference = ordinal
+            .raw()
+            .parse::<usize>()
+            .ok()
+            .and_then(|position| select_targets.get(position.checked_sub(1)?))
+            .and_then(|target| {
+                let mut code = target.segments().iter().filter(|it| it.is_code());
+                match (code.next(), code.next()) {
+                    (Some(column_reference), None)
+                        if column_reference.is_type(SyntaxKind::ColumnReference) =>
+                    {
+                        Some(column_reference.clone())
+                    }
+                    _ => None,
+                }
+            });
+
+        let Some(column_reference) = column_reference else {
+            return Vec::new();
+        };
+
+        fixes.push(LintFix::replace(ordinal.clone(), vec![column_reference], None));
+    }
+
+    fixes
+}
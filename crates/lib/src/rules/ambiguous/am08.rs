@@ -0,0 +1,119 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::dialects::init::DialectKind;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleAM08;
+
+impl RuleAM08 {
+    /// Whether `keyword` marks a join as one that takes no condition.
+    ///
+    /// Beyond the standard `CROSS` and `NATURAL` joins, some dialects have
+    /// their own join types which pair rows without a condition.
+    fn is_unconditioned_join_keyword(dialect: DialectKind, keyword: &str) -> bool {
+        match keyword {
+            "CROSS" | "NATURAL" | "APPLY" | "LATERAL" => true,
+            "PASTE" => dialect == DialectKind::Clickhouse,
+            _ => false,
+        }
+    }
+}
+
+impl Rule for RuleAM08 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAM08.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.join_condition"
+    }
+
+    fn description(&self) -> &'static str {
+        "Join clauses should have a join condition unless they are explicit cross joins."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A `JOIN` without an `ON` or `USING` condition produces a cross join, which is rarely intended when the join type isn't spelled out.
+
+```sql
+SELECT
+    foo.a,
+    bar.b
+FROM foo
+JOIN bar
+```
+
+**Best practice**
+
+Add the join condition, or use `CROSS JOIN` if a cross join is intended.
+
+```sql
+SELECT
+    foo.a,
+    bar.b
+FROM foo
+JOIN bar ON foo.id = bar.foo_id
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let dialect = context.dialect.name();
+
+        let mut keywords =
+            context.segment.segments().iter().filter(|it| it.is_type(SyntaxKind::Keyword));
+        if keywords.any(|keyword| {
+            keyword.is_keyword("USING")
+                || Self::is_unconditioned_join_keyword(dialect, &keyword.raw().to_uppercase())
+        }) {
+            return Vec::new();
+        }
+
+        if context.segment.child(const { SyntaxSet::new(&[SyntaxKind::JoinOnCondition]) }).is_some()
+        {
+            return Vec::new();
+        }
+
+        // Joining onto a table function such as `UNNEST` or a lateral
+        // subquery pairs each row with its own expansion.
+        let joins_function = context
+            .segment
+            .child(const { SyntaxSet::new(&[SyntaxKind::FromExpressionElement]) })
+            .and_then(|element| {
+                element.child(const { SyntaxSet::new(&[SyntaxKind::TableExpression]) })
+            })
+            .is_some_and(|table_expression| {
+                table_expression.child(const { SyntaxSet::new(&[SyntaxKind::Function]) }).is_some()
+                    || table_expression.segments().iter().any(|it| it.is_keyword("LATERAL"))
+            });
+        if joins_function {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            Vec::new(),
+            None,
+            "Join clause has no join condition. Add `ON`/`USING` or use `CROSS JOIN`."
+                .to_string()
+                .into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::JoinClause]) }).into()
+    }
+}
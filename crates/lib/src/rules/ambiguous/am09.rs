@@ -0,0 +1,146 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+use crate::rules::get_ruleset;
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleAM09;
+
+/// Whether `performance.in_subquery` is also selected, remembered per file.
+#[derive(Clone, Copy)]
+struct InSubquerySelected(bool);
+
+impl RuleAM09 {
+    fn in_subquery_selected(context: &RuleContext) -> bool {
+        if let Some(InSubquerySelected(selected)) = context.try_get() {
+            return selected;
+        }
+
+        let selected =
+            context.config.is_some_and(|config| get_ruleset().is_selected(config, "PF01"));
+        context.set(InSubquerySelected(selected));
+        selected
+    }
+}
+
+impl Rule for RuleAM09 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleAM09.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "ambiguous.not_in_subquery"
+    }
+
+    fn description(&self) -> &'static str {
+        "`NOT IN` with a subquery returns no rows if the subquery returns a `NULL`."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+`a NOT IN (SELECT b ...)` is never true if any `b` is `NULL`, as comparing `a` with `NULL` is unknown. A single `NULL` in the subquery silently filters out every row.
+
+```sql
+SELECT a
+FROM foo
+WHERE foo.id NOT IN (SELECT bar.foo_id FROM bar)
+```
+
+**Best practice**
+
+Use `NOT EXISTS`, which isn't affected by `NULL` values.
+
+```sql
+SELECT a
+FROM foo
+WHERE NOT EXISTS (SELECT 1 FROM bar WHERE bar.foo_id = foo.id)
+```
+
+**Overlap with `performance.in_subquery`**
+
+`performance.in_subquery` (PF01) also reports `NOT IN` subqueries, as failures. When both rules are enabled only PF01 reports them, so the same expression isn't flagged twice.
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Ambiguous]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        if Self::in_subquery_selected(&context) {
+            return Vec::new();
+        }
+
+        let code = context.segment.segments().iter().filter(|it| it.is_code()).collect::<Vec<_>>();
+
+        code.windows(3)
+            .filter(|window| {
+                window[0].is_keyword("NOT")
+                    && window[1].is_keyword("IN")
+                    && window[2].is_type(SyntaxKind::Bracketed)
+                    && window[2]
+                        .child(
+                            const {
+                                SyntaxSet::new(&[
+                                    SyntaxKind::SelectStatement,
+                                    SyntaxKind::SetExpression,
+                                    SyntaxKind::WithCompoundStatement,
+                                ])
+                            },
+                        )
+                        .is_some()
+            })
+            .map(|window| {
+                LintResult::new(
+                    window[0].clone().into(),
+                    Vec::new(),
+                    None,
+                    "`NOT IN` with a subquery returns no rows if the subquery returns a `NULL`. \
+                     Use `NOT EXISTS` instead."
+                        .to_string()
+                        .into(),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Expression]) }).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::config::{FluffConfig, Value};
+    use crate::core::linter::linter::Linter;
+
+    #[test]
+    fn test_not_in_subquery_is_left_to_pf01_when_both_are_enabled() {
+        let config = FluffConfig::new(
+            [(
+                "core".into(),
+                Value::Map([("rules".into(), Value::String("AM09,PF01".into()))].into()),
+            )]
+            .into(),
+            None,
+            None,
+        )
+        .unwrap();
+        let linter = Linter::new(config, None, None);
+
+        let violations =
+            linter.check_string("SELECT a FROM foo WHERE b NOT IN (SELECT b FROM bar)\n");
+
+        assert_eq!(
+            violations.iter().map(|violation| violation.rule_code).collect::<Vec<_>>(),
+            ["PF01"]
+        );
+    }
+}
@@ -61,6 +61,8 @@ Ensure all "not equal to" comparisons are consistent, not mixing `!=` and `<>`.
 ```sql
 SELECT * FROM X WHERE 1 != 2 AND 3 != 4;
 ```
+
+In dialects such as `sparksql` which accept `==` as an alias for `=`, the standard `=` is always preferred.
 "#
     }
 
@@ -71,6 +73,28 @@ SELECT * FROM X WHERE 1 != 2 AND 3 != 4;
     fn eval(&self, context: RuleContext) -> Vec<LintResult> {
         // Get the comparison operator children
         let segment = FunctionalContext::new(context.clone()).segment();
+
+        // Spark accepts ``==`` as an alias for ``=``, always prefer the standard form
+        if context.segment.raw() == "==" {
+            let fix = LintFix::replace(
+                context.segment.clone(),
+                vec![SymbolSegment::create(
+                    "=",
+                    None,
+                    SymbolSegmentNewArgs { r#type: SyntaxKind::ComparisonOperator },
+                )],
+                None,
+            );
+
+            return vec![LintResult::new(
+                context.segment.clone().into(),
+                vec![fix],
+                None,
+                "Use `=` instead of `==`.".to_string().into(),
+                None,
+            )];
+        }
+
         let raw_comparison_operators = segment.children(None);
 
         // Only check ``<>`` or ``!=`` operators
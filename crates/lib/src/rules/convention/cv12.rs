@@ -0,0 +1,118 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::dialects::init::DialectKind;
+use crate::core::parser::segments::base::{
+    ErasedSegment, WhitespaceSegment, WhitespaceSegmentNewArgs,
+};
+use crate::core::parser::segments::keyword::KeywordSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+use crate::helpers::ToErasedSegment;
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleCV12;
+
+impl RuleCV12 {
+    fn keyword(raw: &str, lowercase: bool) -> ErasedSegment {
+        let raw = if lowercase { raw.to_lowercase() } else { raw.to_owned() };
+        KeywordSegment::new(raw.into(), None).to_erased_segment()
+    }
+}
+
+impl Rule for RuleCV12 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleCV12.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.add_column_idempotency"
+    }
+
+    fn description(&self) -> &'static str {
+        "ADD COLUMN should use IF NOT EXISTS."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Adding a column without `IF NOT EXISTS` fails if the migration is run a second time.
+
+```sql
+ALTER TABLE foo ADD COLUMN bar INT
+```
+
+**Best practice**
+
+Guard the added column with `IF NOT EXISTS`, so the statement is idempotent.
+
+```sql
+ALTER TABLE foo ADD COLUMN IF NOT EXISTS bar INT
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn dialect_skip(&self) -> &'static [DialectKind] {
+        // These dialects do not parse `ADD COLUMN IF NOT EXISTS`.
+        &[DialectKind::Ansi, DialectKind::Clickhouse, DialectKind::Snowflake, DialectKind::Sqlite]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let mut results = Vec::new();
+
+        for segment in context.segment.recursive_crawl_all(false) {
+            let code: Vec<_> = segment.segments().iter().filter(|it| it.is_code()).collect();
+
+            for window in code.windows(2) {
+                let [add, column] = window else { unreachable!() };
+                if !add.is_keyword("ADD")
+                    || !(column.is_keyword("COLUMN") || column.is_keyword("COLUMNS"))
+                {
+                    continue;
+                }
+
+                let guarded = code
+                    .iter()
+                    .skip_while(|it| !it.is(column))
+                    .nth(1)
+                    .is_some_and(|it| it.is_keyword("IF"));
+                if guarded {
+                    continue;
+                }
+
+                let lowercase = column.raw().chars().all(|c| !c.is_uppercase());
+                let mut edits = Vec::new();
+                for keyword in ["IF", "NOT", "EXISTS"] {
+                    edits.push(WhitespaceSegment::create(" ", None, WhitespaceSegmentNewArgs {}));
+                    edits.push(Self::keyword(keyword, lowercase));
+                }
+
+                results.push(LintResult::new(
+                    (*add).clone().into(),
+                    vec![LintFix::create_after((*column).clone(), edits, None)],
+                    None,
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::AlterTableStatement]) })
+            .into()
+    }
+}
@@ -0,0 +1,170 @@
+use ahash::AHashMap;
+use strum_macros::{AsRefStr, EnumString};
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{
+    CodeSegment, CodeSegmentNewArgs, ErasedSegment, SymbolSegment, SymbolSegmentNewArgs,
+};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Debug, Copy, Clone, AsRefStr, EnumString, PartialEq, Default)]
+#[strum(serialize_all = "snake_case")]
+enum PreferredIntervalStyle {
+    #[default]
+    Consistent,
+    Literal,
+    String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RuleCV13 {
+    preferred_interval_style: PreferredIntervalStyle,
+}
+
+impl RuleCV13 {
+    /// Returns the value of an interval along with its current style, if it
+    /// can be written in either style.
+    fn interval_value(segment: &ErasedSegment) -> Option<(ErasedSegment, PreferredIntervalStyle)> {
+        // `INTERVAL '2-3' YEAR TO MONTH` has no literal equivalent.
+        if segment.segments().iter().any(|it| it.is_keyword("TO")) {
+            return None;
+        }
+
+        let value = segment.segments().iter().find(|it| {
+            matches!(
+                it.get_type(),
+                SyntaxKind::NumericLiteral
+                    | SyntaxKind::QuotedLiteral
+                    | SyntaxKind::SignedQuotedLiteral
+            )
+        })?;
+        if value.is_type(SyntaxKind::NumericLiteral) {
+            return Some((value.clone(), PreferredIntervalStyle::Literal));
+        }
+
+        if value.is_type(SyntaxKind::QuotedLiteral)
+            || value.is_type(SyntaxKind::SignedQuotedLiteral)
+        {
+            let raw = value.raw();
+            let inner = &raw[1..raw.len() - 1];
+            let numeric = !inner.is_empty()
+                && !inner.starts_with('.')
+                && !inner.ends_with('.')
+                && inner.chars().all(|c| c.is_ascii_digit() || c == '.')
+                && inner.matches('.').count() <= 1;
+
+            if numeric {
+                return Some((value.clone(), PreferredIntervalStyle::String));
+            }
+        }
+
+        None
+    }
+}
+
+impl Rule for RuleCV13 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let value = config["preferred_interval_style"].as_string().unwrap();
+        let preferred_interval_style = value
+            .parse()
+            .map_err(|_| format!("Invalid value for preferred_interval_style: {value}"))?;
+
+        Ok(RuleCV13 { preferred_interval_style }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "convention.interval_style"
+    }
+
+    fn description(&self) -> &'static str {
+        "Consistent usage of literal or string interval values."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Spark accepts both `INTERVAL 1 DAY` and `INTERVAL '1' DAY`. Mixing the two in one file is inconsistent.
+
+```sql
+SELECT
+    event_time + INTERVAL 1 DAY AS next_day,
+    event_time + INTERVAL '2' HOUR AS later
+FROM events
+```
+
+**Best practice**
+
+Use a single style, as configured by `preferred_interval_style` (`consistent`, `literal` or `string`). With `consistent`, the first interval in the file sets the style. Intervals which can only be written as a string, such as `INTERVAL '2-3' YEAR TO MONTH`, are ignored.
+
+```sql
+SELECT
+    event_time + INTERVAL 1 DAY AS next_day,
+    event_time + INTERVAL 2 HOUR AS later
+FROM events
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Convention]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some((value, style)) = Self::interval_value(&context.segment) else {
+            return Vec::new();
+        };
+
+        let preferred_interval_style =
+            if self.preferred_interval_style == PreferredIntervalStyle::Consistent {
+                let preferred_interval_style =
+                    context.try_get::<PreferredIntervalStyle>().unwrap_or(style);
+                context.set(preferred_interval_style);
+                preferred_interval_style
+            } else {
+                self.preferred_interval_style
+            };
+
+        if style == preferred_interval_style {
+            return Vec::new();
+        }
+
+        let raw = value.raw();
+        let fixed = match preferred_interval_style {
+            PreferredIntervalStyle::String => SymbolSegment::create(
+                &format!("'{raw}'"),
+                None,
+                SymbolSegmentNewArgs { r#type: SyntaxKind::QuotedLiteral },
+            ),
+            _ => CodeSegment::create(
+                &raw[1..raw.len() - 1],
+                None,
+                CodeSegmentNewArgs { code_type: SyntaxKind::NumericLiteral, ..Default::default() },
+            ),
+        };
+
+        vec![LintResult::new(
+            value.clone().into(),
+            vec![LintFix::replace(value.clone(), vec![fixed.clone()], None)],
+            None,
+            format!(
+                "Interval value should use the {} style: `{}`.",
+                preferred_interval_style.as_ref(),
+                fixed.raw()
+            )
+            .into(),
+            None,
+        )]
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::IntervalLiteral]) }).into()
+    }
+}
@@ -66,3 +66,26 @@ JOIN bar USING (a)
         RootOnlyCrawler.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RuleLT01;
+    use crate::api::simple::{fix, lint};
+    use crate::core::rules::base::Erased;
+
+    #[test]
+    fn test_trailing_whitespace_positions() {
+        let sql = "SELECT  \n    a,\n    'b  '\nFROM foo   \n";
+
+        let violations =
+            lint(sql.into(), "ansi".into(), vec![RuleLT01.erased()], None, None).unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!((violations[0].line_no, violations[0].line_pos), (1, 7));
+        assert_eq!((violations[1].line_no, violations[1].line_pos), (4, 9));
+        assert_eq!(violations[0].description, "Unnecessary trailing whitespace.");
+
+        let fixed = fix(sql, vec![RuleLT01.erased()]);
+        assert_eq!(fixed, "SELECT\n    a,\n    'b  '\nFROM foo\n");
+    }
+}
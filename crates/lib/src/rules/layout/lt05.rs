@@ -2,6 +2,7 @@ use ahash::{AHashMap, AHashSet};
 use itertools::enumerate;
 
 use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
 use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
 use crate::core::rules::context::RuleContext;
 use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
@@ -34,7 +35,7 @@ impl Rule for RuleLT05 {
         r#"
 **Anti-pattern**
 
-In this example, the line is too long.
+In this example, the line is too long. Tabs count as `tab_space_size` characters, and the violation is reported at the first column beyond `max_line_length`.
 
 ```sql
 SELECT
@@ -85,8 +86,7 @@ FROM my_table"#
                 }
 
                 let pos_marker = res.anchor.as_ref().unwrap().get_position_marker().unwrap();
-                let raw_idx =
-                    raw_segments.iter().position(|it| it == res.anchor.as_ref().unwrap()).unwrap();
+                let raw_idx = line_start_idx(&raw_segments, res.anchor.as_ref().unwrap());
 
                 for seg in &raw_segments[raw_idx..] {
                     if seg.get_position_marker().unwrap().working_line_no
@@ -108,8 +108,7 @@ FROM my_table"#
         if self.ignore_comment_clauses {
             let raw_segments = context.segment.get_raw_segments();
             for (res_idx, res) in enumerate(&results) {
-                let raw_idx =
-                    raw_segments.iter().position(|it| it == res.anchor.as_ref().unwrap()).unwrap();
+                let raw_idx = line_start_idx(&raw_segments, res.anchor.as_ref().unwrap());
 
                 for seg in &raw_segments[raw_idx..] {
                     if seg.get_position_marker().unwrap().working_line_no
@@ -172,3 +171,57 @@ FROM my_table"#
         RootOnlyCrawler.into()
     }
 }
+
+/// The index in `raw_segments` of the first segment on the same line as
+/// `anchor`. Results are anchored where the line overflows, but comments
+/// anywhere on the line are considered.
+fn line_start_idx(raw_segments: &[ErasedSegment], anchor: &ErasedSegment) -> usize {
+    let raw_idx = raw_segments.iter().position(|it| it == anchor).unwrap();
+    let line_no = anchor.get_position_marker().unwrap().working_line_no;
+
+    raw_segments[..raw_idx]
+        .iter()
+        .rposition(|seg| seg.get_position_marker().unwrap().working_line_no != line_no)
+        .map_or(0, |idx| idx + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuleLT05;
+    use crate::api::simple::lint;
+    use crate::core::errors::SQLBaseError;
+    use crate::core::rules::base::Erased;
+
+    fn lint_lt05(sql: String) -> Vec<SQLBaseError> {
+        lint(sql, "ansi".into(), vec![RuleLT05::default().erased()], None, None).unwrap()
+    }
+
+    #[test]
+    fn test_reports_first_column_over_limit() {
+        let sql = "SELECT first_column, second_column, third_column, fourth_column, fifth_column \
+                   FROM my_table\n";
+
+        let violations = lint_lt05(sql.into());
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].description, "Line is too long (91 > 80).");
+        assert_eq!(violations[0].line_no, 1);
+        // The first column past the limit, inside `FROM`.
+        assert_eq!(violations[0].line_pos, 81);
+    }
+
+    #[test]
+    fn test_tabs_count_as_tab_space_size() {
+        let at_limit = format!("SELECT\n\t\t{}\nFROM my_table\n", "a".repeat(72));
+        let over_limit = format!("SELECT\n\t\t\t{}\nFROM my_table\n", "a".repeat(72));
+
+        assert!(lint_lt05(at_limit).is_empty());
+
+        let violations = lint_lt05(over_limit);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].description, "Line is too long (84 > 80).");
+        assert_eq!(violations[0].line_no, 2);
+        // The three tabs take up 12 columns, so column 81 is the 69th `a`.
+        assert_eq!(violations[0].line_pos, 3 + 69);
+    }
+}
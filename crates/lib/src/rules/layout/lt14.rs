@@ -0,0 +1,131 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, RootOnlyCrawler};
+use crate::dialects::SyntaxKind;
+use crate::utils::reflow::reindent::{construct_single_indent, display_len, IndentUnit};
+
+#[derive(Default, Debug, Clone)]
+pub struct RuleLT14;
+
+impl RuleLT14 {
+    /// Rewrites an indent using the configured indent unit, preserving its
+    /// visual width.
+    fn fixed_indent(raw: &str, indent_unit: IndentUnit, tab_space_size: usize) -> String {
+        let width = display_len(raw, tab_space_size);
+        let single_indent = construct_single_indent(indent_unit);
+        let unit_width = display_len(&single_indent, tab_space_size);
+
+        single_indent.repeat(width / unit_width) + &" ".repeat(width % unit_width)
+    }
+}
+
+impl Rule for RuleLT14 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleLT14.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "layout.tabs"
+    }
+
+    fn description(&self) -> &'static str {
+        "Indentation should not contain tabs or mixed tabs and spaces."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+The ``•`` character represents a space and the ``→`` character represents a tab.
+In this example, the second line is indented with a tab and the third line with a mix of tabs and spaces.
+Only indentation is checked; tabs between tokens are left to `layout.spacing`.
+
+```sql
+SELECT
+→a,
+••→b
+FROM foo
+```
+
+**Best practice**
+
+Use the configured `indent_unit` consistently. This example assumes that the indent_unit config value is set to space, in which case each tab is replaced by `tab_space_size` spaces.
+
+```sql
+SELECT
+••••a,
+••••••b
+FROM foo
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Layout]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let reflow_config = context.config.unwrap().reflow();
+        let indent_unit = reflow_config.indent_unit;
+        let tab_space_size = reflow_config.tab_space_size;
+
+        let raw_segments = context.segment.get_raw_segments();
+        let mut results = Vec::new();
+        for (idx, segment) in raw_segments.iter().enumerate() {
+            if !segment.is_type(SyntaxKind::Whitespace) {
+                continue;
+            }
+
+            // Only indents are checked: whitespace that starts a line and
+            // precedes something on it. Trailing whitespace belongs to LT01.
+            let starts_line = idx == 0 || raw_segments[idx - 1].is_type(SyntaxKind::Newline);
+            let ends_line = raw_segments.get(idx + 1).map_or(true, |it| {
+                it.is_type(SyntaxKind::Newline) || it.is_type(SyntaxKind::EndOfFile)
+            });
+            if !starts_line || ends_line {
+                continue;
+            }
+
+            let raw = segment.raw();
+            if !raw.contains('\t') {
+                continue;
+            }
+
+            let description = match indent_unit {
+                IndentUnit::Space(_) => "Indentation contains tabs, expected spaces.",
+                IndentUnit::Tab if raw.contains(' ') => "Indentation mixes tabs and spaces.",
+                IndentUnit::Tab => continue,
+            };
+
+            let fixed = Self::fixed_indent(&raw, indent_unit, tab_space_size);
+            if fixed == raw {
+                continue;
+            }
+
+            results.push(LintResult::new(
+                segment.clone().into(),
+                vec![LintFix::replace(
+                    segment.clone(),
+                    vec![segment.edit(fixed.into(), None)],
+                    None,
+                )],
+                None,
+                description.to_string().into(),
+                None,
+            ));
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        RootOnlyCrawler.into()
+    }
+}
@@ -0,0 +1,152 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Default, Debug, Clone)]
+pub struct RuleLT15 {
+    modifier_order: Vec<String>,
+}
+
+impl RuleLT15 {
+    /// The category of a segment which starts a select clause modifier, as
+    /// named in the `modifier_order` configuration.
+    fn category(segment: &ErasedSegment) -> Option<&'static str> {
+        if segment.is_type(SyntaxKind::SelectHint) {
+            return Some("hint");
+        }
+
+        match segment.get_raw_upper()?.as_str() {
+            "DISTINCT" | "ALL" => Some("distinct"),
+            "TOP" => Some("top"),
+            _ => None,
+        }
+    }
+
+    /// Splits the children of a select clause modifier into groups which
+    /// each start at a configured modifier and run up to the last code
+    /// segment before the next one, along with their canonical position.
+    fn modifier_groups(&self, modifier: &ErasedSegment) -> Vec<(usize, Vec<ErasedSegment>)> {
+        let mut groups: Vec<(usize, Vec<ErasedSegment>)> = Vec::new();
+
+        for segment in modifier.segments() {
+            let rank = Self::category(segment)
+                .and_then(|category| self.modifier_order.iter().position(|it| it == category));
+
+            match (rank, groups.last_mut()) {
+                (Some(rank), _) => groups.push((rank, vec![segment.clone()])),
+                (None, Some((_, group))) => group.push(segment.clone()),
+                (None, None) => {}
+            }
+        }
+
+        for (_, group) in &mut groups {
+            while group.last().is_some_and(|it| !it.is_code()) {
+                group.pop();
+            }
+        }
+
+        groups
+    }
+}
+
+impl Rule for RuleLT15 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleLT15 {
+            modifier_order: config["modifier_order"]
+                .as_array()
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|it| it.as_string())
+                .map(|it| it.trim().to_lowercase())
+                .collect(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "layout.select_modifier_order"
+    }
+
+    fn description(&self) -> &'static str {
+        "SELECT modifiers should be in the configured order."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+In this example, the hint follows the `DISTINCT` modifier.
+
+```sql
+SELECT DISTINCT /*+ REPARTITION(3) */
+    a
+FROM foo
+```
+
+**Best practice**
+
+Write modifiers in the order given by the `modifier_order` configuration, which defaults to `hint, distinct, top`.
+
+```sql
+SELECT /*+ REPARTITION(3) */ DISTINCT
+    a
+FROM foo
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Layout]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let groups = self.modifier_groups(&context.segment);
+
+        let mut expected = groups.clone();
+        expected.sort_by_key(|(rank, _)| *rank);
+
+        let mut fixes = Vec::new();
+        let mut anchor = None;
+        for ((rank, actual), (expected_rank, replacement)) in groups.iter().zip(&expected) {
+            if rank == expected_rank {
+                continue;
+            }
+
+            anchor.get_or_insert_with(|| actual[0].clone());
+            fixes.push(LintFix::replace(actual[0].clone(), replacement.clone(), None));
+            fixes.extend(actual[1..].iter().cloned().map(LintFix::delete));
+        }
+
+        let Some(anchor) = anchor else {
+            return Vec::new();
+        };
+
+        let order = expected
+            .iter()
+            .map(|(rank, _)| self.modifier_order[*rank].to_uppercase())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        vec![LintResult::new(
+            anchor.into(),
+            fixes,
+            None,
+            format!("SELECT modifiers should be ordered as {order}.").into(),
+            None,
+        )]
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectClauseModifier]) })
+            .into()
+    }
+}
@@ -0,0 +1,10 @@
+use crate::core::rules::base::ErasedRule;
+
+pub mod pf01;
+pub mod pf02;
+
+pub fn rules() -> Vec<ErasedRule> {
+    use crate::core::rules::base::Erased as _;
+
+    vec![pf01::RulePF01.erased(), pf02::RulePF02.erased()]
+}
@@ -0,0 +1,130 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+use crate::helpers::Config;
+
+const SUBQUERY_TYPES: SyntaxSet = SyntaxSet::new(&[
+    SyntaxKind::SelectStatement,
+    SyntaxKind::SetExpression,
+    SyntaxKind::WithCompoundStatement,
+]);
+
+#[derive(Debug, Default, Clone)]
+pub struct RulePF01;
+
+impl Rule for RulePF01 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RulePF01.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "performance.in_subquery"
+    }
+
+    fn description(&self) -> &'static str {
+        "IN and NOT IN subqueries may be better written as joins."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Filtering with `IN (SELECT ...)` can often be rewritten as a join, which many engines execute more efficiently. This is reported as a warning.
+
+`NOT IN (SELECT ...)` is reported as a failure, because if the subquery returns any `NULL` value then no rows match at all.
+
+```sql
+SELECT a
+FROM foo
+WHERE b NOT IN (SELECT b FROM bar)
+```
+
+**Best practice**
+
+Use a join, or `NOT EXISTS` in place of `NOT IN`.
+
+```sql
+SELECT foo.a
+FROM foo
+LEFT JOIN bar ON foo.b = bar.b
+WHERE bar.b IS NULL
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Performance]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let children = context.segment.segments();
+        let mut results = Vec::new();
+
+        for (idx, child) in children.iter().enumerate() {
+            if !child.is_keyword("IN") {
+                continue;
+            }
+
+            let Some(bracketed) = children[idx + 1..].iter().find(|it| it.is_code()) else {
+                continue;
+            };
+            if !bracketed.is_type(SyntaxKind::Bracketed)
+                || bracketed.child(SUBQUERY_TYPES).is_none()
+            {
+                continue;
+            }
+
+            let negated = children[..idx]
+                .iter()
+                .rev()
+                .find(|it| it.is_code())
+                .is_some_and(|it| it.is_keyword("NOT"));
+
+            let description = if negated {
+                "NOT IN subquery matches no rows if the subquery returns NULL, use NOT EXISTS or a \
+                 join instead."
+            } else {
+                "IN subquery may be better written as a join."
+            };
+
+            results.push(
+                LintResult::new(
+                    bracketed.clone().into(),
+                    Vec::new(),
+                    None,
+                    description.to_string().into(),
+                    None,
+                )
+                .config(|this| this.warning = !negated),
+            );
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Expression]) }).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RulePF01;
+    use crate::api::simple::lint;
+    use crate::core::rules::base::Erased;
+
+    #[test]
+    fn test_in_subquery_is_warning_and_not_in_subquery_is_failure() {
+        let sql =
+            "SELECT a FROM foo WHERE b IN (SELECT b FROM bar) AND c NOT IN (SELECT c FROM baz)\n";
+
+        let violations =
+            lint(sql.into(), "ansi".into(), vec![RulePF01.erased()], None, None).unwrap();
+
+        assert_eq!(violations.iter().map(|it| it.warning).collect::<Vec<_>>(), [true, false]);
+    }
+}
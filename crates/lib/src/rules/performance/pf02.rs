@@ -0,0 +1,155 @@
+use ahash::{AHashMap, AHashSet};
+use smol_str::{SmolStr, StrExt};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::ansi::ObjectReferenceLevel;
+use crate::dialects::{SyntaxKind, SyntaxSet};
+use crate::helpers::Config;
+use crate::utils::analysis::select::get_select_statement_info;
+
+#[derive(Debug, Default, Clone)]
+pub struct RulePF02;
+
+impl Rule for RulePF02 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RulePF02.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "performance.correlated_select_subquery"
+    }
+
+    fn description(&self) -> &'static str {
+        "Correlated subqueries in the select list may be better written as joins."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A subquery in the select list which references a table of the outer query is evaluated once per row by many engines. This is reported as a warning.
+
+Only table qualified references to the outer query are detected.
+
+```sql
+SELECT
+    a.id,
+    (SELECT count(*) FROM b WHERE b.a_id = a.id) AS b_count
+FROM a
+```
+
+**Best practice**
+
+Aggregate the subquery once and join it to the outer query.
+
+```sql
+SELECT
+    a.id,
+    b_counts.b_count
+FROM a
+LEFT JOIN (
+    SELECT a_id, count(*) AS b_count FROM b GROUP BY a_id
+) AS b_counts ON b_counts.a_id = a.id
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Performance]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let Some(select_clause) =
+            context.segment.child(const { SyntaxSet::single(SyntaxKind::SelectClause) })
+        else {
+            return Vec::new();
+        };
+
+        let Some(select_info) =
+            get_select_statement_info(&context.segment, context.dialect.into(), true)
+        else {
+            return Vec::new();
+        };
+
+        let outer_sources: AHashSet<SmolStr> = select_info
+            .table_aliases
+            .iter()
+            .filter(|alias| !alias.ref_str.is_empty())
+            .map(|alias| alias.ref_str.to_uppercase_smolstr())
+            .collect();
+        if outer_sources.is_empty() {
+            return Vec::new();
+        }
+
+        let mut results = Vec::new();
+
+        for nested_select in select_clause.recursive_crawl(
+            const { SyntaxSet::single(SyntaxKind::SelectStatement) },
+            false,
+            None,
+            false,
+        ) {
+            let Some(nested_info) =
+                get_select_statement_info(&nested_select, context.dialect.into(), true)
+            else {
+                continue;
+            };
+
+            let inner_sources: AHashSet<SmolStr> = nested_info
+                .table_aliases
+                .iter()
+                .map(|alias| alias.ref_str.to_uppercase_smolstr())
+                .collect();
+
+            let correlated = nested_info.reference_buffer.iter().any(|reference| {
+                reference
+                    .extract_possible_references(ObjectReferenceLevel::Table, context.dialect.name)
+                    .into_iter()
+                    .map(|table| table.part.to_uppercase_smolstr())
+                    .any(|table| outer_sources.contains(&table) && !inner_sources.contains(&table))
+            });
+
+            if correlated {
+                results.push(
+                    LintResult::new(
+                        nested_select.into(),
+                        Vec::new(),
+                        None,
+                        "Correlated subquery in select list may be better written as a join."
+                            .to_string()
+                            .into(),
+                        None,
+                    )
+                    .config(|this| this.warning = true),
+                );
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::single(SyntaxKind::SelectStatement) }).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RulePF02;
+    use crate::api::simple::lint;
+    use crate::core::rules::base::Erased;
+
+    #[test]
+    fn test_correlated_select_subquery_is_warning() {
+        let sql = "SELECT (SELECT count(*) FROM b WHERE b.id = a.id) AS c FROM a\n";
+
+        let violations =
+            lint(sql.into(), "ansi".into(), vec![RulePF02.erased()], None, None).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].warning);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::core::rules::base::ErasedRule;
+
+pub mod pt01;
+pub mod pt02;
+
+pub fn rules() -> Vec<ErasedRule> {
+    use crate::core::rules::base::Erased as _;
+
+    vec![pt01::RulePT01::default().erased(), pt02::RulePT02.erased()]
+}
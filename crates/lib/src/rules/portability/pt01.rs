@@ -0,0 +1,125 @@
+use ahash::{AHashMap, AHashSet};
+use itertools::Itertools;
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+use crate::helpers::Config;
+
+#[derive(Debug, Default, Clone)]
+pub struct RulePT01 {
+    future_keywords: AHashSet<String>,
+}
+
+impl Rule for RulePT01 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        let invalid = || "Rule PT01 expects `future_keywords` to be a list of keywords".to_string();
+        let future_keywords = config["future_keywords"]
+            .map(|it| {
+                it.as_array()
+                    .ok_or_else(invalid)?
+                    .iter()
+                    .map(|it| it.as_string().map(|it| it.trim().to_uppercase()).ok_or_else(invalid))
+                    .filter_ok(|it| !it.is_empty())
+                    .collect::<Result<AHashSet<_>, _>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(RulePT01 { future_keywords }.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "portability.future_keywords"
+    }
+
+    fn description(&self) -> &'static str {
+        "Unquoted identifiers should not use future reserved keywords."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Engines announce words which will become reserved in later versions. Using one as an unquoted identifier works today but breaks after an upgrade. This is reported as a warning.
+
+The words are set with `future_keywords`, a comma separated list which is empty by default. Configure it for the dialect and engine version being targeted. In this example it contains `qualify`.
+
+```sql
+SELECT qualify
+FROM foo
+```
+
+**Best practice**
+
+Quote the identifier, or rename it.
+
+```sql
+SELECT "qualify"
+FROM foo
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Portability]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let raw = context.segment.raw();
+        if !self.future_keywords.contains(&raw.to_uppercase()) {
+            return Vec::new();
+        }
+
+        vec![
+            LintResult::new(
+                context.segment.clone().into(),
+                Vec::new(),
+                None,
+                format!("Unquoted identifier '{raw}' will be a reserved keyword.").into(),
+                None,
+            )
+            .config(|this| this.warning = true),
+        ]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::NakedIdentifier]) }).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ahash::{AHashMap, AHashSet};
+
+    use super::RulePT01;
+    use crate::api::simple::lint;
+    use crate::core::config::Value;
+    use crate::core::rules::base::{Erased, Rule};
+
+    #[test]
+    fn test_unquoted_future_keyword_is_warning() {
+        let rule = RulePT01 { future_keywords: AHashSet::from_iter(["QUALIFY".to_string()]) };
+
+        let violations = lint(
+            "SELECT qualify FROM foo\n".into(),
+            "ansi".into(),
+            vec![rule.erased()],
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].warning);
+    }
+
+    #[test]
+    fn test_non_string_future_keywords_is_an_error() {
+        let config = AHashMap::from_iter([("future_keywords".to_string(), Value::Int(2025))]);
+
+        assert!(RulePT01::default().load_from_config(&config).is_err());
+    }
+}
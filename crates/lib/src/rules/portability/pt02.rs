@@ -0,0 +1,114 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::dialects::init::DialectKind;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct RulePT02;
+
+impl RulePT02 {
+    /// Whether `dialect` only accepts a single expression in
+    /// `COUNT(DISTINCT ...)`.
+    fn rejects_multiple_columns(dialect: DialectKind) -> bool {
+        matches!(
+            dialect,
+            DialectKind::Bigquery
+                | DialectKind::Duckdb
+                | DialectKind::Postgres
+                | DialectKind::Sqlite
+        )
+    }
+}
+
+impl Rule for RulePT02 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RulePT02.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "portability.count_distinct_columns"
+    }
+
+    fn description(&self) -> &'static str {
+        "`COUNT(DISTINCT ...)` over several columns is not supported by this dialect."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Counting the distinct combinations of several columns with `COUNT(DISTINCT a, b)` works in some engines, such as Spark, but is an error in others. This rule only reports it for the dialects which reject it: BigQuery, DuckDB, PostgreSQL and SQLite.
+
+```sql
+SELECT COUNT(DISTINCT a, b)
+FROM foo
+```
+
+**Best practice**
+
+Count the rows of a subquery which selects the distinct combinations.
+
+```sql
+SELECT COUNT(*)
+FROM (
+    SELECT DISTINCT a, b
+    FROM foo
+) AS combinations
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Portability]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        if !Self::rejects_multiple_columns(context.dialect.name()) {
+            return Vec::new();
+        }
+
+        let is_count = context
+            .segment
+            .child(const { SyntaxSet::new(&[SyntaxKind::FunctionName]) })
+            .is_some_and(|name| name.get_raw_upper().as_deref() == Some("COUNT"));
+        if !is_count {
+            return Vec::new();
+        }
+
+        let Some(bracketed) =
+            context.segment.child(const { SyntaxSet::new(&[SyntaxKind::Bracketed]) })
+        else {
+            return Vec::new();
+        };
+
+        let mut contents = bracketed.segments().iter().filter(|it| it.is_code());
+        if !contents.any(|it| it.is_keyword("DISTINCT")) {
+            return Vec::new();
+        }
+
+        if contents.filter(|it| it.is_type(SyntaxKind::Expression)).count() < 2 {
+            return Vec::new();
+        }
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            Vec::new(),
+            None,
+            format!(
+                "`COUNT(DISTINCT ...)` over several columns is not supported by {}. Count the \
+                 rows of a `SELECT DISTINCT` subquery instead.",
+                context.dialect.name()
+            )
+            .into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::Function]) }).into()
+    }
+}
@@ -119,6 +119,11 @@ impl RuleRF02 {
 
         let mut violation_buff = Vec::new();
         for r in references {
+            // A bare wildcard selects from every table, so there is nothing to qualify.
+            if r.0.get_type() == SyntaxKind::WildcardIdentifier {
+                continue;
+            }
+
             if context.0.contains(&r.0.raw().to_lowercase()) {
                 continue;
             }
@@ -0,0 +1,165 @@
+use ahash::{AHashMap, AHashSet};
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::ansi::SelectClauseElementSegment;
+use crate::dialects::{SyntaxKind, SyntaxSet};
+use crate::utils::functional::context::FunctionalContext;
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleST10;
+
+impl RuleST10 {
+    fn normalise(segment: &ErasedSegment) -> String {
+        segment.raw().chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase()
+    }
+
+    /// The last part of a qualified column reference such as `foo.a`.
+    fn qualified_last_part(segment: &ErasedSegment) -> Option<&ErasedSegment> {
+        if !segment.is_type(SyntaxKind::ColumnReference)
+            || !segment.segments().iter().any(|it| it.is_type(SyntaxKind::Dot))
+        {
+            return None;
+        }
+
+        segment.segments().iter().rfind(|it| it.is_code())
+    }
+}
+
+impl Rule for RuleST10 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST10.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.distinct_order_by"
+    }
+
+    fn description(&self) -> &'static str {
+        "ORDER BY expressions must appear in the SELECT DISTINCT list."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+With `SELECT DISTINCT`, ordering by a column which is not selected is invalid in strict SQL, as each distinct row may correspond to many values of that column.
+
+```sql
+SELECT DISTINCT
+    a
+FROM foo
+ORDER BY b
+```
+
+**Best practice**
+
+Only order by expressions which appear in the select list.
+
+```sql
+SELECT DISTINCT
+    a
+FROM foo
+ORDER BY a
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let segment = FunctionalContext::new(context.clone()).segment();
+
+        let select_clause = segment.children(Some(|it| it.is_type(SyntaxKind::SelectClause)));
+        let distinct = select_clause
+            .children(Some(|it| it.is_type(SyntaxKind::SelectClauseModifier)))
+            .children(Some(|it| {
+                it.is_type(SyntaxKind::Keyword) && it.get_raw_upper().unwrap() == "DISTINCT"
+            }));
+        if distinct.is_empty() {
+            return Vec::new();
+        }
+
+        let Some(orderby_clause) =
+            segment.children(Some(|it| it.is_type(SyntaxKind::OrderbyClause))).first().cloned()
+        else {
+            return Vec::new();
+        };
+
+        let select_elements =
+            select_clause.children(Some(|it| it.is_type(SyntaxKind::SelectClauseElement)));
+
+        // Selected expressions and names, including the last part of qualified
+        // column references, which bare ORDER BY references are matched against.
+        let mut allowed = AHashSet::new();
+        // Selected bare column references, which qualified ORDER BY references
+        // are matched against by their last part.
+        let mut bare = AHashSet::new();
+        for element in select_elements.iter() {
+            if element.child(const { SyntaxSet::new(&[SyntaxKind::WildcardExpression]) }).is_some()
+            {
+                // The wildcard may expand to any column, so nothing can be reported.
+                return Vec::new();
+            }
+
+            match SelectClauseElementSegment(element.clone()).alias() {
+                Some(alias_info) => {
+                    allowed.insert(alias_info.alias_identifier_name.to_uppercase());
+                    allowed.insert(Self::normalise(&alias_info.aliased_segment));
+                }
+                None => {
+                    for child in element.segments() {
+                        if !child.is_code() {
+                            continue;
+                        }
+                        allowed.insert(Self::normalise(child));
+                        match Self::qualified_last_part(child) {
+                            Some(last) => {
+                                allowed.insert(Self::normalise(last));
+                            }
+                            None if child.is_type(SyntaxKind::ColumnReference) => {
+                                bare.insert(Self::normalise(child));
+                            }
+                            None => {}
+                        }
+                    }
+                }
+            }
+        }
+
+        orderby_clause
+            .segments()
+            .iter()
+            .filter(|it| {
+                it.is_type(SyntaxKind::ColumnReference) || it.is_type(SyntaxKind::Expression)
+            })
+            .filter(|it| {
+                !allowed.contains(&Self::normalise(it))
+                    && !Self::qualified_last_part(it)
+                        .is_some_and(|last| bare.contains(&Self::normalise(last)))
+            })
+            .map(|it| {
+                LintResult::new(
+                    it.clone().into(),
+                    Vec::new(),
+                    None,
+                    format!(
+                        "ORDER BY expression `{}` is not in the SELECT DISTINCT list.",
+                        it.raw()
+                    )
+                    .into(),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::SelectStatement]) }).into()
+    }
+}
@@ -0,0 +1,125 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+const COMMENT_TYPES: SyntaxSet =
+    SyntaxSet::new(&[SyntaxKind::Comment, SyntaxKind::InlineComment, SyntaxKind::BlockComment]);
+
+#[derive(Default, Debug, Clone)]
+pub struct RuleST11 {
+    allow_comment: String,
+}
+
+impl RuleST11 {
+    fn is_allowed(&self, comment: &ErasedSegment) -> bool {
+        !self.allow_comment.is_empty()
+            && comment.raw().to_lowercase().contains(&self.allow_comment.to_lowercase())
+    }
+
+    /// Comments directly preceding the top level statement which contains
+    /// the segment, e.g. on the line above.
+    fn preceding_comments(context: &RuleContext) -> Vec<ErasedSegment> {
+        let [file, statement, ..] = context.parent_stack.as_slice() else {
+            return Vec::new();
+        };
+        let Some(idx) = file.segments().iter().position(|it| it.is(statement)) else {
+            return Vec::new();
+        };
+
+        file.segments()[..idx]
+            .iter()
+            .rev()
+            .take_while(|it| !it.is_code())
+            .filter(|it| COMMENT_TYPES.contains(it.get_type()))
+            .cloned()
+            .collect()
+    }
+}
+
+impl Rule for RuleST11 {
+    fn load_from_config(&self, config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST11 {
+            allow_comment: config["allow_comment"].as_string().unwrap_or_default().to_owned(),
+        }
+        .erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.missing_where"
+    }
+
+    fn description(&self) -> &'static str {
+        "DELETE and UPDATE statements should have a WHERE clause."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A `DELETE` or `UPDATE` without a `WHERE` clause affects every row in the table, which is rarely intended.
+
+```sql
+DELETE FROM foo
+```
+
+**Best practice**
+
+Restrict the rows affected with a `WHERE` clause.
+
+```sql
+DELETE FROM foo
+WHERE a = 1
+```
+
+Intentional full table operations can be allowed with a comment containing the `allow_comment` configuration value, either within the statement or directly before it.
+
+```sql
+-- allow_full_table
+DELETE FROM foo
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        if context.segment.child(const { SyntaxSet::new(&[SyntaxKind::WhereClause]) }).is_some() {
+            return Vec::new();
+        }
+
+        let allowed = context
+            .segment
+            .recursive_crawl(COMMENT_TYPES, false, None, false)
+            .into_iter()
+            .chain(Self::preceding_comments(&context))
+            .any(|comment| self.is_allowed(&comment));
+        if allowed {
+            return Vec::new();
+        }
+
+        let statement =
+            if context.segment.is_type(SyntaxKind::DeleteStatement) { "DELETE" } else { "UPDATE" };
+
+        vec![LintResult::new(
+            context.segment.clone().into(),
+            Vec::new(),
+            None,
+            format!("{statement} statement without a WHERE clause affects every row.").into(),
+            None,
+        )]
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(
+            const { SyntaxSet::new(&[SyntaxKind::DeleteStatement, SyntaxKind::UpdateStatement]) },
+        )
+        .into()
+    }
+}
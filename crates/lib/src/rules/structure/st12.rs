@@ -0,0 +1,98 @@
+use ahash::{AHashMap, AHashSet};
+
+use crate::core::config::Value;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST12;
+
+impl Rule for RuleST12 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST12.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.duplicate_cte"
+    }
+
+    fn description(&self) -> &'static str {
+        "CTE names should be unique within a WITH clause."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Defining the same CTE name twice in one `WITH` clause is ambiguous, and rejected by most engines. Names are compared case-insensitively.
+
+```sql
+WITH cte AS (
+    SELECT a FROM foo
+),
+CTE AS (
+    SELECT b FROM bar
+)
+SELECT * FROM cte
+```
+
+**Best practice**
+
+Give each CTE a distinct name.
+
+```sql
+WITH foo_cte AS (
+    SELECT a FROM foo
+),
+bar_cte AS (
+    SELECT b FROM bar
+)
+SELECT * FROM foo_cte
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let mut seen = AHashSet::new();
+        let mut results = Vec::new();
+
+        for cte in
+            context.segment.children(const { SyntaxSet::new(&[SyntaxKind::CommonTableExpression]) })
+        {
+            let Some(name_segment) = cte.segments().first() else {
+                continue;
+            };
+            let name = name_segment
+                .raw()
+                .trim_matches(|c| matches!(c, '"' | '`' | '[' | ']'))
+                .to_uppercase();
+
+            if !seen.insert(name) {
+                results.push(LintResult::new(
+                    name_segment.clone().into(),
+                    Vec::new(),
+                    None,
+                    format!(
+                        "CTE \"{}\" is already defined in this WITH clause.",
+                        name_segment.raw()
+                    )
+                    .into(),
+                    None,
+                ));
+            }
+        }
+
+        results
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::WithCompoundStatement]) })
+            .into()
+    }
+}
@@ -0,0 +1,115 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::{SymbolSegment, SymbolSegmentNewArgs};
+use crate::core::rules::base::{Erased, ErasedRule, LintFix, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST13;
+
+impl Rule for RuleST13 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST13.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.body_terminators"
+    }
+
+    fn description(&self) -> &'static str {
+        "Statements in a trigger body should each end with a single semicolon."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+Within a `CREATE TRIGGER` body, each statement must be terminated by exactly one semicolon. Stray double semicolons, or a final statement without one before `END`, are errors.
+
+```sql
+CREATE TRIGGER update_log AFTER UPDATE ON foo
+BEGIN
+    INSERT INTO log VALUES (new.a);;
+    DELETE FROM bar WHERE a = new.a
+END;
+```
+
+**Best practice**
+
+Terminate each statement in the body with a single semicolon.
+
+```sql
+CREATE TRIGGER update_log AFTER UPDATE ON foo
+BEGIN
+    INSERT INTO log VALUES (new.a);
+    DELETE FROM bar WHERE a = new.a;
+END;
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let segments = context.segment.segments();
+        let Some(begin) = segments.iter().position(|it| it.is_keyword("BEGIN")) else {
+            return Vec::new();
+        };
+        let Some(end) = segments.iter().rposition(|it| it.is_keyword("END")) else {
+            return Vec::new();
+        };
+
+        let mut results = Vec::new();
+        let mut last_code = &segments[begin];
+
+        for segment in segments[begin + 1..end].iter().filter(|it| it.is_code()) {
+            if segment.is_type(SyntaxKind::StatementTerminator)
+                && (last_code.is_type(SyntaxKind::StatementTerminator)
+                    || last_code.is_keyword("BEGIN"))
+            {
+                results.push(LintResult::new(
+                    segment.clone().into(),
+                    vec![LintFix::delete(segment.clone())],
+                    None,
+                    "Unnecessary semicolon in trigger body.".to_string().into(),
+                    None,
+                ));
+            }
+            last_code = segment;
+        }
+
+        if !last_code.is_type(SyntaxKind::StatementTerminator) && !last_code.is_keyword("BEGIN") {
+            results.push(LintResult::new(
+                last_code.clone().into(),
+                vec![LintFix::create_after(
+                    last_code.clone(),
+                    vec![SymbolSegment::create(
+                        ";",
+                        None,
+                        SymbolSegmentNewArgs { r#type: SyntaxKind::StatementTerminator },
+                    )],
+                    None,
+                )],
+                None,
+                "Missing semicolon before END of trigger body.".to_string().into(),
+                None,
+            ));
+        }
+
+        results
+    }
+
+    fn is_fix_compatible(&self) -> bool {
+        true
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::CreateTriggerStatement]) })
+            .into()
+    }
+}
@@ -0,0 +1,102 @@
+use ahash::{AHashMap, AHashSet};
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST14;
+
+impl Rule for RuleST14 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST14.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.duplicate_partition_column"
+    }
+
+    fn description(&self) -> &'static str {
+        "Partition columns should not also be declared in the table schema."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+When `PARTITIONED BY` declares columns with their types, those columns are added to the table. Declaring the same column in the main column list as well is rejected by the engine. Names are compared case-insensitively.
+
+```sql
+CREATE TABLE student (id INT, name STRING, age INT)
+PARTITIONED BY (age INT)
+```
+
+**Best practice**
+
+Declare partition columns only in `PARTITIONED BY`, or list them there by name only.
+
+```sql
+CREATE TABLE student (id INT, name STRING)
+PARTITIONED BY (age INT)
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let mut schema_columns = AHashSet::new();
+        let mut partition_columns = Vec::new();
+        let mut after_partitioned_by = false;
+        let mut previous_code: Option<&ErasedSegment> = None;
+
+        for child in context.segment.segments() {
+            if !child.is_code() {
+                continue;
+            }
+
+            if child.is_type(SyntaxKind::Bracketed) {
+                let columns = child
+                    .children(const { SyntaxSet::new(&[SyntaxKind::ColumnDefinition]) })
+                    .into_iter()
+                    .filter_map(|column| column.segments().first().cloned());
+
+                if after_partitioned_by {
+                    partition_columns.extend(columns);
+                } else if schema_columns.is_empty() {
+                    schema_columns.extend(columns.map(|name| normalise(&name.raw())));
+                }
+            }
+
+            after_partitioned_by = child.is_keyword("BY")
+                && previous_code.is_some_and(|it| it.is_keyword("PARTITIONED"));
+            previous_code = Some(child);
+        }
+
+        partition_columns
+            .into_iter()
+            .filter(|name| schema_columns.contains(&normalise(&name.raw())))
+            .map(|name| {
+                let description = format!(
+                    "Partition column \"{}\" is already declared in the table schema.",
+                    name.raw()
+                );
+                LintResult::new(name.into(), Vec::new(), None, description.into(), None)
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::CreateTableStatement]) })
+            .into()
+    }
+}
+
+fn normalise(name: &str) -> String {
+    name.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']')).to_uppercase()
+}
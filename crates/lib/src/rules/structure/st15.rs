@@ -0,0 +1,131 @@
+use ahash::AHashMap;
+
+use crate::core::config::Value;
+use crate::core::parser::segments::base::ErasedSegment;
+use crate::core::rules::base::{Erased, ErasedRule, LintResult, Rule, RuleGroups};
+use crate::core::rules::context::RuleContext;
+use crate::core::rules::crawlers::{Crawler, SegmentSeekerCrawler};
+use crate::dialects::{SyntaxKind, SyntaxSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct RuleST15;
+
+impl RuleST15 {
+    /// Collects the wildcards which contribute to the output columns of a
+    /// view body, descending through brackets, set operations and the final
+    /// select of a `WITH` statement.
+    fn output_wildcards(segment: &ErasedSegment, wildcards: &mut Vec<ErasedSegment>) {
+        match segment.get_type() {
+            SyntaxKind::SelectStatement => {
+                for element in segment
+                    .children(const { SyntaxSet::new(&[SyntaxKind::SelectClause]) })
+                    .into_iter()
+                    .flat_map(|clause| {
+                        clause
+                            .children(const { SyntaxSet::new(&[SyntaxKind::SelectClauseElement]) })
+                    })
+                {
+                    wildcards.extend(
+                        element.child(const { SyntaxSet::new(&[SyntaxKind::WildcardExpression]) }),
+                    );
+                }
+            }
+            SyntaxKind::WithCompoundStatement => {
+                if let Some(body) = segment.segments().iter().rfind(|it| {
+                    it.is_type(SyntaxKind::SelectStatement)
+                        || it.is_type(SyntaxKind::SetExpression)
+                        || it.is_type(SyntaxKind::Bracketed)
+                }) {
+                    Self::output_wildcards(body, wildcards);
+                }
+            }
+            SyntaxKind::SetExpression | SyntaxKind::Bracketed => {
+                for child in segment.segments() {
+                    Self::output_wildcards(child, wildcards);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Rule for RuleST15 {
+    fn load_from_config(&self, _config: &AHashMap<String, Value>) -> Result<ErasedRule, String> {
+        Ok(RuleST15.erased())
+    }
+
+    fn name(&self) -> &'static str {
+        "structure.view_wildcard"
+    }
+
+    fn description(&self) -> &'static str {
+        "Views selecting a wildcard should declare their column list."
+    }
+
+    fn long_description(&self) -> &'static str {
+        r#"
+**Anti-pattern**
+
+A view defined with `SELECT *` silently changes shape whenever the underlying table does, which can break consumers of the view.
+
+```sql
+CREATE VIEW active_users AS
+SELECT * FROM users
+WHERE active
+```
+
+**Best practice**
+
+Declare the view's columns explicitly, either in a column list or in the select itself.
+
+```sql
+CREATE VIEW active_users (id, name) AS
+SELECT * FROM users
+WHERE active
+```
+"#
+    }
+
+    fn groups(&self) -> &'static [RuleGroups] {
+        &[RuleGroups::All, RuleGroups::Structure]
+    }
+
+    fn eval(&self, context: RuleContext) -> Vec<LintResult> {
+        let mut children = context
+            .segment
+            .segments()
+            .iter()
+            .filter(|it| it.is_code())
+            .skip_while(|it| !it.is_type(SyntaxKind::TableReference))
+            .skip(1)
+            .peekable();
+
+        // A bracketed list directly after the view name is its column list.
+        if children.peek().is_some_and(|it| it.is_type(SyntaxKind::Bracketed)) {
+            return Vec::new();
+        }
+
+        let mut wildcards = Vec::new();
+        if let Some(body) = children.skip_while(|it| !it.is_keyword("AS")).nth(1) {
+            Self::output_wildcards(body, &mut wildcards);
+        }
+
+        wildcards
+            .into_iter()
+            .map(|wildcard| {
+                LintResult::new(
+                    wildcard.into(),
+                    Vec::new(),
+                    None,
+                    "View selects a wildcard without an explicit column list.".to_string().into(),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    fn crawl_behaviour(&self) -> Crawler {
+        SegmentSeekerCrawler::new(const { SyntaxSet::new(&[SyntaxKind::CreateViewStatement]) })
+            .into()
+    }
+}
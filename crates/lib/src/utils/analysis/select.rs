@@ -47,6 +47,7 @@ pub fn get_select_statement_info(
     let sc = segment.child(const { SyntaxSet::new(&[SyntaxKind::SelectClause]) })?;
     let mut reference_buffer = get_object_references(&sc);
     for potential_clause in [
+        SyntaxKind::PrewhereClause,
         SyntaxKind::WhereClause,
         SyntaxKind::GroupbyClause,
         SyntaxKind::HavingClause,
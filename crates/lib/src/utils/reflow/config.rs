@@ -91,6 +91,7 @@ pub struct ReflowConfig {
     /// use `.from_fluff_config`, but the defaults are here to aid in
     /// testing.
     pub(crate) indent_unit: IndentUnit,
+    pub(crate) tab_space_size: usize,
     pub(crate) max_line_length: usize,
     pub(crate) hanging_indents: bool,
     pub(crate) allow_implicit_indents: bool,
@@ -247,6 +248,7 @@ impl ReflowConfig {
             configs,
             config_types,
             indent_unit,
+            tab_space_size,
             max_line_length: config.raw["core"]["max_line_length"].as_int().unwrap() as usize,
             hanging_indents: config.raw["indentation"]["hanging_indents"]
                 .as_bool()
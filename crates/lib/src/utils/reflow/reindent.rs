@@ -9,6 +9,7 @@ use strum_macros::EnumString;
 use super::elements::{ReflowBlock, ReflowElement, ReflowPoint, ReflowSequenceType};
 use super::helpers::fixes_from_results;
 use super::rebreak::{identify_rebreak_spans, LinePosition, RebreakSpan};
+use crate::core::parser::markers::PositionMarker;
 use crate::core::parser::segments::base::{
     ErasedSegment, NewlineSegment, WhitespaceSegment, WhitespaceSegmentNewArgs,
 };
@@ -837,7 +838,14 @@ pub fn lint_indent_points(
     (elem_buffer, results)
 }
 
-fn source_char_len(elements: &[ReflowElement]) -> usize {
+/// The width of `text` on screen, counting each tab as `tab_space_size`
+/// characters.
+pub(crate) fn display_len(text: &str, tab_space_size: usize) -> usize {
+    let tabs = text.matches('\t').count();
+    text.len() - tabs + tabs * tab_space_size
+}
+
+fn source_char_len(elements: &[ReflowElement], tab_space_size: usize) -> usize {
     let mut char_len = 0;
     let mut last_source_slice = None;
 
@@ -854,7 +862,7 @@ fn source_char_len(elements: &[ReflowElement]) -> usize {
         let source_str = pos_marker.source_str();
 
         if let Some(pos) = source_str.find('\n') {
-            char_len += pos;
+            char_len += display_len(&source_str[..pos], tab_space_size);
             break;
         }
 
@@ -862,14 +870,14 @@ fn source_char_len(elements: &[ReflowElement]) -> usize {
 
         if Some(source_slice.clone()) != last_source_slice {
             if !seg.raw().is_empty() && slice_len == 0 {
-                char_len += seg.raw().len();
+                char_len += display_len(&seg.raw(), tab_space_size);
             } else if slice_len == 0 {
                 continue;
             } else if pos_marker.is_literal() {
-                char_len += seg.raw().len();
+                char_len += display_len(&seg.raw(), tab_space_size);
                 last_source_slice = Some(source_slice);
             } else {
-                char_len += source_slice.end - source_slice.start;
+                char_len += display_len(source_str, tab_space_size);
                 last_source_slice = Some(source_slice);
             }
         }
@@ -878,6 +886,62 @@ fn source_char_len(elements: &[ReflowElement]) -> usize {
     char_len
 }
 
+/// The first character on the line beyond `line_length_limit`, counting tabs
+/// as `tab_space_size` characters, as a point on the segment containing it.
+/// This way the violation points at where the line overflows rather than where
+/// it starts.
+fn first_point_over_limit(
+    line_buffer: &[ReflowElement],
+    indent_len: usize,
+    line_length_limit: usize,
+    tab_space_size: usize,
+) -> Option<ErasedSegment> {
+    let mut line_len = indent_len;
+
+    for seg in line_buffer.iter().flat_map(|elem| elem.segments()) {
+        if seg.is_type(SyntaxKind::Indent) || seg.is_type(SyntaxKind::Dedent) {
+            continue;
+        }
+
+        let raw = seg.raw();
+        let seg_len = display_len(&raw, tab_space_size);
+        if line_len + seg_len <= line_length_limit {
+            line_len += seg_len;
+            continue;
+        }
+
+        let Some(marker) = seg.get_position_marker() else {
+            return Some(seg.clone());
+        };
+
+        // Only literal segments map their characters one to one onto the
+        // source, otherwise fall back to the start of the segment.
+        if marker.source_slice.len() != raw.len() || marker.templated_slice.len() != raw.len() {
+            return Some(seg.clone());
+        }
+
+        let offset = raw
+            .char_indices()
+            .find(|&(_, ch)| {
+                line_len += if ch == '\t' { tab_space_size } else { ch.len_utf8() };
+                line_len > line_length_limit
+            })
+            .map_or(0, |(idx, _)| idx);
+
+        let mut anchor = seg.deep_clone();
+        anchor.get_mut().set_position_marker(Some(PositionMarker::from_point(
+            marker.source_slice.start + offset,
+            marker.templated_slice.start + offset,
+            marker.templated_file,
+            None,
+            None,
+        )));
+        return Some(anchor);
+    }
+
+    None
+}
+
 fn rebreak_priorities(spans: Vec<RebreakSpan>) -> AHashMap<usize, usize> {
     let mut rebreak_priority = AHashMap::with_capacity(spans.len());
 
@@ -1182,6 +1246,7 @@ pub fn lint_line_length(
     root_segment: &ErasedSegment,
     single_indent: &str,
     line_length_limit: usize,
+    tab_space_size: usize,
     allow_implicit_indents: bool,
     trailing_comments: TrailingComments,
 ) -> (ReflowSequenceType, Vec<LintResult>) {
@@ -1215,8 +1280,9 @@ pub fn lint_line_length(
             "".into()
         };
 
-        let char_len = source_char_len(&line_buffer);
-        let line_len = current_indent.len() + char_len;
+        let indent_len = display_len(&current_indent, tab_space_size);
+        let char_len = source_char_len(&line_buffer, tab_space_size);
+        let line_len = indent_len + char_len;
 
         let first_seg = line_buffer[0].segments()[0].clone();
         let line_no = first_seg.get_position_marker().unwrap().working_line_no;
@@ -1293,7 +1359,11 @@ pub fn lint_line_length(
                 fixes = fixes_from_results(line_results.into_iter()).collect();
             }
 
-            results.push(LintResult::new(first_seg.into(), fixes, None, desc.into(), None))
+            let anchor =
+                first_point_over_limit(&line_buffer, indent_len, line_length_limit, tab_space_size)
+                    .unwrap_or(first_seg);
+
+            results.push(LintResult::new(anchor.into(), fixes, None, desc.into(), None))
         }
 
         line_buffer.clear();
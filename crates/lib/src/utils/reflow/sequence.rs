@@ -387,6 +387,7 @@ impl<'a> ReflowSequence<'a> {
             &self.root_segment,
             &single_indent,
             self.reflow_config.max_line_length,
+            self.reflow_config.tab_space_size,
             self.reflow_config.allow_implicit_indents,
             self.reflow_config.trailing_comments,
         );
@@ -0,0 +1,31 @@
+CREATE TABLE measurements (
+    city_id INT NOT NULL,
+    logdate DATE NOT NULL,
+    peaktemp INT
+) PARTITION BY RANGE (logdate);
+
+CREATE TABLE cities (
+    city_id INT NOT NULL,
+    name VARCHAR(100)
+) PARTITION BY LIST (name);
+
+CREATE TABLE orders (
+    order_id INT NOT NULL,
+    customer_id INT
+) PARTITION BY HASH (order_id);
+
+CREATE TABLE sales (
+    region VARCHAR(10),
+    sale_year INT,
+    amount DECIMAL(10, 2)
+) PARTITION BY RANGE COLUMNS (sale_year, region);
+
+CREATE TABLE customers (
+    customer_id INT,
+    country VARCHAR(2)
+) PARTITION BY LIST COLUMNS (country);
+
+CREATE TABLE events (
+    event_id INT,
+    created_at TIMESTAMP
+) PARTITION BY RANGE (EXTRACT(YEAR FROM created_at));
@@ -0,0 +1,5 @@
+EXPLAIN SELECT a FROM tbl;
+
+EXPLAIN ANALYZE SELECT a FROM tbl WHERE b = 1;
+
+EXPLAIN ANALYZE UPDATE tbl SET a = 1 WHERE b = 2;
@@ -0,0 +1,11 @@
+SELECT a FROM t ORDER BY a OFFSET 10 ROWS FETCH NEXT 5 ROWS ONLY;
+
+SELECT a FROM t ORDER BY a OFFSET 1 ROW FETCH FIRST 1 ROW ONLY;
+
+SELECT a FROM t OFFSET 10 ROWS;
+
+SELECT a FROM t WHERE a > 1 OFFSET 10 ROWS FETCH NEXT 5 ROWS ONLY;
+
+SELECT a, COUNT(*) FROM t GROUP BY a HAVING COUNT(*) > 1 OFFSET 2 ROWS;
+
+SELECT a FROM t ORDER BY a FETCH FIRST 3 ROWS WITH TIES;
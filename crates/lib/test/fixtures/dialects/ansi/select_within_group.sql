@@ -0,0 +1,10 @@
+SELECT percentile_cont(0.5) WITHIN GROUP (ORDER BY x) AS median_x
+FROM t;
+
+SELECT percentile_cont(0.5) WITHIN GROUP (ORDER BY x) FILTER (WHERE y > 0) AS median_x
+FROM t;
+
+SELECT
+    listagg(name, ',') WITHIN GROUP (ORDER BY name DESC) AS names
+FROM t
+GROUP BY category;
@@ -0,0 +1,7 @@
+TRUNCATE TABLE t RESTART IDENTITY CASCADE;
+
+TRUNCATE TABLE t CONTINUE IDENTITY RESTRICT;
+
+TRUNCATE t CASCADE;
+
+TRUNCATE TABLE t RESTART IDENTITY;
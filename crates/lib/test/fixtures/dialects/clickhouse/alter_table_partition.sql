@@ -0,0 +1,25 @@
+ALTER TABLE visits FREEZE PARTITION tuple();
+
+ALTER TABLE visits FREEZE;
+
+ALTER TABLE visits FREEZE PARTITION 201902 WITH NAME 'backup_1';
+
+ALTER TABLE visits UNFREEZE PARTITION 201902 WITH NAME 'backup_1';
+
+ALTER TABLE visits DETACH PARTITION 201901;
+
+ALTER TABLE visits DETACH PART 'all_2_2_0';
+
+ALTER TABLE visits ATTACH PARTITION 201901;
+
+ALTER TABLE visits ATTACH PARTITION ID '201901' FROM visits_staging;
+
+ALTER TABLE visits ON CLUSTER main DROP PARTITION '2019-01-01';
+
+ALTER TABLE visits DROP DETACHED PARTITION ALL;
+
+ALTER TABLE visits MOVE PARTITION p TO TABLE other;
+
+ALTER TABLE visits MOVE PART 'all_1_1_0' TO DISK 'fast_ssd';
+
+ALTER TABLE visits MOVE PARTITION 201902 TO VOLUME 'cold';
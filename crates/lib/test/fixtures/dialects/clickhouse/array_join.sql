@@ -0,0 +1,23 @@
+SELECT s, x, y
+FROM arrays_test
+ARRAY JOIN arr AS x, arrayEnumerate(arr) AS y;
+
+SELECT s, x, y
+FROM arrays_test
+LEFT ARRAY JOIN arr AS x, arrayEnumerate(arr) AS y
+WHERE y > 1;
+
+SELECT s, x, y
+FROM arrays_test AS t
+ARRAY JOIN t.arr AS x
+ARRAY JOIN t.nested AS y
+ORDER BY s;
+
+SELECT a.s, x
+FROM arrays_test AS a
+INNER JOIN other AS b ON a.s = b.s
+LEFT ARRAY JOIN a.arr AS x;
+
+SELECT s, n.x, n.y
+FROM nested_test
+ARRAY JOIN nest AS n, [1, 2, 3] AS num;
@@ -0,0 +1,7 @@
+SELECT [1, 2, 3] AS arr, (1, 'a') AS tup, map('k', 1) AS m, [] AS empty_arr;
+
+SELECT a FROM t WHERE a IN [1, 2, 3];
+
+SELECT has([1, 2], a), arr[1] FROM t;
+
+SELECT a FROM t WHERE a NOT IN ['x', 'y'] AND b IN (1, 2);
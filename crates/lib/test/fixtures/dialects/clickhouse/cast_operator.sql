@@ -0,0 +1,9 @@
+SELECT a::String FROM t;
+
+SELECT a::Int32::String AS b FROM t;
+
+SELECT CAST(a AS String), (a + 1)::UInt64 FROM t;
+
+SELECT a FROM t WHERE b::Date > '2020-01-01';
+
+SELECT '1'::Nullable(Int32), a::Array(String) FROM t;
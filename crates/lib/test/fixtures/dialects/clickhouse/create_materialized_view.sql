@@ -48,3 +48,21 @@ AS
         column1,
         column2
     FROM table_kafka;
+
+CREATE MATERIALIZED VIEW table_mv
+REFRESH EVERY 1 DAY
+TO table
+AS
+    SELECT
+        column1,
+        column2
+    FROM table_kafka;
+
+CREATE MATERIALIZED VIEW table_mv
+REFRESH AFTER 1 HOUR 30 MINUTE APPEND
+TO table
+AS
+    SELECT
+        column1,
+        column2
+    FROM table_kafka;
@@ -0,0 +1,13 @@
+CREATE TABLE t1 (id UInt64, name String) ENGINE = MergeTree() ORDER BY id;
+
+CREATE TABLE t2 (id UInt64, ver UInt32) ENGINE = ReplacingMergeTree(ver) ORDER BY id;
+
+CREATE TABLE t3 ON CLUSTER my_cluster (id UInt64)
+ENGINE = ReplicatedMergeTree('/clickhouse/tables/{shard}/db/t3', '{replica}')
+ORDER BY id;
+
+CREATE TABLE t4 (id UInt64) ENGINE = Distributed(my_cluster, db, t3, rand());
+
+CREATE TABLE t5 (id UInt64) ENGINE = Distributed('my_cluster', 'db', 't3', cityHash64(id));
+
+CREATE TABLE t6 (id UInt64) ENGINE = Distributed(my_cluster, currentDatabase(), t3);
@@ -0,0 +1,22 @@
+CREATE USER john IDENTIFIED WITH sha256_password BY 'qwerty';
+
+CREATE USER IF NOT EXISTS mira
+ON CLUSTER '{cluster}'
+IDENTIFIED WITH plaintext_password BY 'secret'
+HOST IP '127.0.0.1', LIKE '%.example.com'
+DEFAULT ROLE ALL EXCEPT accountant;
+
+CREATE USER OR REPLACE reader NOT IDENTIFIED HOST LOCAL DEFAULT ROLE reader_role DEFAULT DATABASE analytics;
+
+CREATE ROLE accountant;
+
+CREATE ROLE IF NOT EXISTS accountant, auditor ON CLUSTER my_cluster;
+
+CREATE QUOTA qA FOR INTERVAL 15 month MAX queries = 123 TO CURRENT_USER;
+
+CREATE QUOTA OR REPLACE qB ON CLUSTER my_cluster
+KEYED BY user_name
+FOR INTERVAL 30 minute MAX execution_time = 0.5, FOR INTERVAL 5 quarter MAX queries = 321, errors = 10
+TO ALL EXCEPT default;
+
+CREATE QUOTA qC NOT KEYED FOR RANDOMIZED INTERVAL 1 day TRACKING ONLY;
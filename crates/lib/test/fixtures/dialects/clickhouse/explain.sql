@@ -0,0 +1,15 @@
+EXPLAIN SELECT a FROM t;
+
+EXPLAIN AST SELECT a FROM t;
+
+EXPLAIN SYNTAX SELECT a FROM t WHERE a > 1;
+
+EXPLAIN QUERY TREE SELECT a FROM t;
+
+EXPLAIN PLAN indexes = 1 SELECT a FROM t WHERE a = 1;
+
+EXPLAIN PLAN header = 1, actions = 1 SELECT a FROM t;
+
+EXPLAIN PIPELINE SELECT sum(a) FROM t GROUP BY b;
+
+EXPLAIN ESTIMATE SELECT * FROM t;
@@ -0,0 +1,36 @@
+SELECT a
+FROM my_table FINAL SAMPLE 0.1
+WHERE a > 0;
+
+SELECT a
+FROM my_table AS x FINAL;
+
+SELECT a
+FROM my_table x FINAL
+WHERE x.a > 0;
+
+SELECT a
+FROM (SELECT a FROM my_table) FINAL;
+
+SELECT a
+FROM (SELECT a FROM my_table) AS sub FINAL;
+
+SELECT a
+FROM my_table AS x FINAL SAMPLE 1/10 OFFSET 1/2;
+
+SELECT a.id, b.value
+FROM events AS a FINAL
+INNER JOIN dimensions AS b FINAL ON a.id = b.id;
+
+SELECT a.id, b.value
+FROM events FINAL
+LEFT JOIN dimensions FINAL USING (id);
+
+SELECT a.id, sub.total
+FROM events AS a FINAL
+JOIN (SELECT id, sum(x) AS total FROM facts GROUP BY id) AS sub FINAL ON a.id = sub.id;
+
+WITH latest AS (SELECT id, value FROM dimensions)
+SELECT e.id, latest.value
+FROM events AS e FINAL
+JOIN latest FINAL ON e.id = latest.id;
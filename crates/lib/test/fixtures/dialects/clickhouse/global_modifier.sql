@@ -0,0 +1,11 @@
+SELECT * FROM test1 GLOBAL JOIN test2 ON test2.ty1 = test1.ty1;
+SELECT * FROM test1 GLOBAL LEFT JOIN test2 ON test2.ty1 = test1.ty1;
+SELECT * FROM test1 AS t1 GLOBAL ANY INNER JOIN test2 USING ty1;
+SELECT * FROM test1 AS t1 GLOBAL LEFT ANTI JOIN test2 AS t2 ON t1.id = t2.id;
+SELECT * FROM test1 WHERE id GLOBAL IN (SELECT id FROM test2);
+SELECT * FROM test1 WHERE id GLOBAL IN (1, 2, 3);
+SELECT * FROM test1 WHERE id GLOBAL NOT IN (SELECT id FROM test2);
+SELECT uniq(user_id) FROM distributed_table
+WHERE
+    counter_id = 34
+    AND user_id GLOBAL IN (SELECT user_id FROM distributed_table WHERE counter_id = 101500);
@@ -0,0 +1,26 @@
+SELECT domain, count() AS hits
+FROM visits
+GROUP BY domain WITH TOTALS;
+
+SELECT year, month, sum(amount) AS total
+FROM sales
+GROUP BY year, month WITH ROLLUP;
+
+SELECT year, month, sum(amount) AS total
+FROM sales
+GROUP BY year, month WITH CUBE;
+
+SELECT year, month, sum(amount) AS total
+FROM sales
+GROUP BY year, month WITH ROLLUP WITH TOTALS;
+
+SELECT year, month, sum(amount) AS total
+FROM sales
+GROUP BY year, month WITH CUBE WITH TOTALS
+HAVING total > 100
+ORDER BY year, month
+LIMIT 10;
+
+SELECT year, month, sum(amount) AS total
+FROM sales
+GROUP BY ROLLUP(year, month) WITH TOTALS;
@@ -67,3 +67,7 @@ SELECT * FROM (SELECT [1, 2] AS arr, [3, 4] AS arr2) AS t1 ARRAY JOIN arr, arr2;
 SELECT x, y FROM (SELECT [1, 2] AS arr, [3, 4] AS arr2) AS t1 ARRAY JOIN arr AS x, arr2 AS y;
 SELECT *,ch,cg FROM (SELECT 1) ARRAY JOIN ['1','2'] as cg, splitByChar(',','1,2') as ch;
 SELECT * FROM (SELECT [1,2] x) AS t1 ARRAY JOIN t1.*;
+
+SELECT a.x, b.y
+FROM a
+PASTE JOIN b;
@@ -1,3 +1,11 @@
 SELECT arrayFirst(x -> x = 2, [1, 1, 2, 2]);
 
 SELECT arrayFirst(x, y -> x != y, [1, 1, 2, 2], [1, 2, 2, 3]);
+
+SELECT arrayMap(x -> x * 2, [1, 2, 3]);
+
+SELECT arrayMap((x, y) -> x + y, [1, 2, 3], [4, 5, 6]);
+
+SELECT arrayFilter(x -> x LIKE '%World%', ['Hello', 'abc World']);
+
+SELECT arrayFilter((x, y) -> y > 1, [1, 2, 3], [0, 1, 2]);
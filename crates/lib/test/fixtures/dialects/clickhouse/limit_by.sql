@@ -0,0 +1,11 @@
+SELECT user_id, event FROM events ORDER BY ts DESC LIMIT 1 BY user_id;
+
+SELECT user_id, event FROM events LIMIT 2 BY user_id, event;
+
+SELECT user_id, event FROM events LIMIT 1, 2 BY user_id;
+
+SELECT user_id, event FROM events LIMIT 2 OFFSET 1 BY user_id;
+
+SELECT user_id FROM events LIMIT 10 OFFSET 5;
+
+SELECT user_id FROM events LIMIT 5, 10;
@@ -0,0 +1,5 @@
+SELECT 1_000, 1_000_000, 1_000.5, 1e3, .5;
+
+SELECT 0xFF, 0xFF_FF, 0b1010_1010;
+
+SELECT a + 1_000 FROM t WHERE b > 0x1F;
@@ -0,0 +1,15 @@
+SELECT a, b
+FROM my_table
+PREWHERE a > 0
+WHERE b = 'x';
+
+SELECT a
+FROM my_table AS t FINAL
+PREWHERE t.a IN (1, 2, 3)
+WHERE t.b IS NOT NULL
+ORDER BY a
+LIMIT 10;
+
+SELECT count()
+FROM my_table
+PREWHERE (a > 0 AND b < 10);
@@ -0,0 +1,14 @@
+SELECT a FROM t SETTINGS max_threads = 8;
+
+SELECT a, count() FROM t WHERE b > 1 GROUP BY a ORDER BY a LIMIT 10
+SETTINGS max_threads = 8, optimize_read_in_order = 0;
+
+SELECT a
+FROM (SELECT a FROM t SETTINGS max_block_size = 1024) AS sub
+SETTINGS max_threads = 2;
+
+INSERT INTO t SELECT a, b FROM src SETTINGS async_insert = 1;
+
+INSERT INTO t (a, b) SETTINGS async_insert = 1, wait_for_async_insert = 0 VALUES (1, 2);
+
+INSERT INTO TABLE db.t VALUES (1, 'x');
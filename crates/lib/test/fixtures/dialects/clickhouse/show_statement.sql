@@ -0,0 +1,25 @@
+SHOW DATABASES;
+
+SHOW DATABASES LIKE '%db%' LIMIT 10;
+
+SHOW TABLES;
+
+SHOW TABLES FROM db LIKE '%x%';
+
+SHOW TEMPORARY TABLES IN db NOT ILIKE 'tmp%';
+
+SHOW CREATE TABLE t;
+
+SHOW CREATE TEMPORARY TABLE db.t;
+
+SHOW CREATE VIEW v;
+
+SHOW CREATE DATABASE db;
+
+SHOW DICTIONARIES FROM db;
+
+SHOW PROCESSLIST;
+
+SHOW FULL PROCESSLIST;
+
+SHOW SETTINGS LIKE 'send_timeout';
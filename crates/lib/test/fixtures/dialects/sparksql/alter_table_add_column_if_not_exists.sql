@@ -0,0 +1,3 @@
+ALTER TABLE student ADD COLUMN IF NOT EXISTS LastName STRING;
+
+ALTER TABLE student ADD COLUMNS IF NOT EXISTS (LastName STRING, DOB TIMESTAMP);
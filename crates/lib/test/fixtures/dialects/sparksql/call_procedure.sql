@@ -0,0 +1,15 @@
+CALL cat.system.rewrite_data_files(table => 'db.t');
+
+CALL cat.system.rewrite_data_files(
+    table => 'db.t',
+    strategy => 'sort',
+    sort_order => 'id DESC NULLS LAST'
+);
+
+CALL cat.system.rollback_to_snapshot('db.t', 1);
+
+CALL cat.system.expire_snapshots(
+    table => 'db.t', older_than => TIMESTAMP '2024-01-01 00:00:00.000', retain_last => 10
+);
+
+CALL cat.system.remove_orphan_files();
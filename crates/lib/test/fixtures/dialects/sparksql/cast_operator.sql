@@ -0,0 +1,9 @@
+SELECT a::STRING FROM t;
+
+SELECT a::INT::STRING AS b FROM t;
+
+SELECT CAST(a AS STRING), (a + 1)::BIGINT FROM t;
+
+SELECT a FROM t WHERE b::DATE > '2020-01-01';
+
+SELECT a::DECIMAL(10, 2), c::ARRAY<INT> FROM t;
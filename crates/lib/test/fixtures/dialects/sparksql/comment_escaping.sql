@@ -0,0 +1,32 @@
+-- Doubled single quotes inside a comment
+CREATE TABLE student (
+    id INT COMMENT 'it''s the id',
+    name STRING COMMENT 'student''s ''full'' name'
+)
+USING PARQUET
+COMMENT 'it''s fine';
+
+-- Backslash-escaped quotes
+CREATE TABLE student (id INT COMMENT 'it\'s the id')
+USING PARQUET
+COMMENT "a \"quoted\" table";
+
+-- Unicode comments
+CREATE TABLE etudiant (
+    id INT COMMENT 'identifiant unique — clé',
+    nom STRING COMMENT '名前'
+)
+USING PARQUET
+COMMENT 'table des étudiants 🎓';
+
+CREATE TABLE student (id INT, name STRING)
+COMMENT 'it''s partitioned'
+PARTITIONED BY (age INT COMMENT 'âge en années, it''s an int');
+
+CREATE VIEW student_view (id COMMENT 'it''s the id', name COMMENT '名前')
+COMMENT 'vue des étudiants'
+AS SELECT id, name FROM student;
+
+ALTER TABLE student ALTER COLUMN name COMMENT 'it''s the name — 名前';
+
+CREATE DATABASE school COMMENT 'it''s a database 🏫';
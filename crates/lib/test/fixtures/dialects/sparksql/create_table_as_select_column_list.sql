@@ -0,0 +1,7 @@
+CREATE TABLE t (x, y) AS SELECT a, b FROM s;
+
+CREATE TABLE IF NOT EXISTS t (x, y)
+USING PARQUET
+AS SELECT a, b FROM s;
+
+CREATE TABLE t (x, y) AS (SELECT a, b FROM s);
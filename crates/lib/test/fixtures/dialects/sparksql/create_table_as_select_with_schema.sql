@@ -0,0 +1,17 @@
+CREATE TABLE student_copy (id COMMENT 'student id', name COMMENT 'it''s the name')
+AS SELECT id, name FROM student;
+
+CREATE OR REPLACE TABLE student_copy (
+    id COMMENT 'student id',
+    name
+)
+USING DELTA
+COMMENT 'copy of student'
+AS SELECT id, name FROM student;
+
+CREATE TABLE student_copy (id INT COMMENT 'student id', name STRING COMMENT 'name')
+USING PARQUET
+AS SELECT id, name FROM student;
+
+CREATE TABLE student_copy (id, name)
+AS SELECT id, name FROM student;
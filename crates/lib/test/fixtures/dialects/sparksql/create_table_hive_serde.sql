@@ -0,0 +1,55 @@
+CREATE EXTERNAL TABLE IF NOT EXISTS db.events (
+    id BIGINT,
+    payload STRING
+)
+PARTITIONED BY (dt STRING)
+ROW FORMAT SERDE 'org.apache.hadoop.hive.serde2.lazy.LazySimpleSerDe'
+WITH SERDEPROPERTIES ('field.delim' = ',', 'serialization.format' = ',')
+STORED AS INPUTFORMAT 'org.apache.hadoop.mapred.TextInputFormat'
+OUTPUTFORMAT 'org.apache.hadoop.hive.ql.io.HiveIgnoreKeyTextOutputFormat'
+LOCATION 's3://bucket/events/'
+TBLPROPERTIES ('skip.header.line.count' = '1');
+
+CREATE TABLE avro_events
+ROW FORMAT SERDE 'org.apache.hadoop.hive.serde2.avro.AvroSerDe'
+STORED AS INPUTFORMAT 'org.apache.hadoop.hive.ql.io.avro.AvroContainerInputFormat'
+OUTPUTFORMAT 'org.apache.hadoop.hive.ql.io.avro.AvroContainerOutputFormat'
+TBLPROPERTIES ('avro.schema.url' = 's3://bucket/schema.avsc');
+
+CREATE TABLE events_orc (id INT)
+STORED AS ORC
+ROW FORMAT SERDE 'org.apache.hadoop.hive.ql.io.orc.OrcSerde'
+WITH SERDEPROPERTIES ('serialization.format' = '1')
+COMMENT 'orc events'
+LOCATION '/data/events_orc';
+
+CREATE TABLE events_csv (id INT, name STRING)
+COMMENT 'csv events'
+ROW FORMAT SERDE 'org.apache.hadoop.hive.serde2.OpenCSVSerde'
+WITH SERDEPROPERTIES (
+    'separatorChar' = ',',
+    'quoteChar' = '"'
+)
+STORED AS TEXTFILE
+LOCATION '/data/events_csv'
+TBLPROPERTIES ('skip.header.line.count' = '1');
+
+CREATE TABLE events_parquet (id INT)
+PARTITIONED BY (dt STRING)
+CLUSTERED BY (id) INTO 4 BUCKETS
+ROW FORMAT SERDE 'org.apache.hadoop.hive.ql.io.parquet.serde.ParquetHiveSerDe'
+STORED AS PARQUET
+LOCATION '/data/events_parquet';
+
+CREATE TABLE events_located (id INT)
+LOCATION '/data/events_located'
+ROW FORMAT SERDE 'org.apache.hadoop.hive.serde2.lazy.LazySimpleSerDe'
+STORED AS TEXTFILE;
+
+CREATE TABLE events_props (id INT)
+TBLPROPERTIES ('transactional' = 'false')
+ROW FORMAT SERDE 'org.apache.hadoop.hive.serde2.lazy.LazySimpleSerDe'
+WITH SERDEPROPERTIES ('field.delim' = '\t')
+STORED AS INPUTFORMAT 'org.apache.hadoop.mapred.TextInputFormat'
+OUTPUTFORMAT 'org.apache.hadoop.hive.ql.io.HiveIgnoreKeyTextOutputFormat'
+LOCATION '/data/events_props';
@@ -20,3 +20,12 @@ CREATE TABLE student_dupli LIKE student
 ROW FORMAT DELIMITED FIELDS TERMINATED BY ','
 STORED AS TEXTFILE
 TBLPROPERTIES ('owner' = 'xxxx');
+
+-- Create table like using a data source at a location
+CREATE TABLE student_dupli LIKE student USING DELTA LOCATION '/x';
+
+-- Create table like with location and table properties
+CREATE TABLE student_dupli LIKE student
+USING PARQUET
+LOCATION '/root1/home'
+TBLPROPERTIES ('owner' = 'xxxx');
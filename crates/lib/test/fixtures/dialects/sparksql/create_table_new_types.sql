@@ -0,0 +1,18 @@
+CREATE TABLE events (
+    id BIGINT,
+    created_at TIMESTAMP_NTZ,
+    seen_at TIMESTAMP_LTZ,
+    payload VARIANT,
+    nothing VOID
+) USING DELTA;
+
+SELECT
+    CAST(created_at AS TIMESTAMP_LTZ) AS created_local,
+    CAST(NULL AS VOID) AS empty,
+    PARSE_JSON('{"a": 1}') AS doc
+FROM events;
+
+CREATE TABLE nested (
+    items ARRAY<VARIANT>,
+    attributes MAP<STRING, TIMESTAMP_NTZ>
+);
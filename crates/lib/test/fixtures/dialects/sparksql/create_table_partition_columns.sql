@@ -0,0 +1,11 @@
+CREATE TABLE student (id INT, name STRING)
+PARTITIONED BY (age INT, grade STRING);
+
+CREATE TABLE student (id INT, name STRING, age INT)
+USING PARQUET
+PARTITIONED BY (age);
+
+CREATE EXTERNAL TABLE student (id INT, name STRING)
+PARTITIONED BY (age INT COMMENT 'age in years')
+STORED AS PARQUET
+LOCATION '/data/student';
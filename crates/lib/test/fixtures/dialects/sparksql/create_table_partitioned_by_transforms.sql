@@ -0,0 +1,20 @@
+CREATE TABLE prod.db.sample (
+    id BIGINT,
+    data STRING,
+    category STRING,
+    ts TIMESTAMP
+)
+USING iceberg
+PARTITIONED BY (days(ts), bucket(16, id), category);
+
+CREATE TABLE prod.db.sample (id BIGINT, data STRING, category STRING, ts TIMESTAMP)
+PARTITIONED BY (category, truncate(10, data), years(ts));
+
+CREATE TABLE prod.db.sample
+USING iceberg
+PARTITIONED BY (bucket(16, id), months(ts), category)
+AS SELECT id, ts, category FROM prod.db.source;
+
+CREATE TABLE prod.db.sample (id BIGINT, ts TIMESTAMP)
+USING parquet
+PARTITIONED BY (hours(ts), id);
@@ -0,0 +1,19 @@
+CREATE TABLE student (id INT, name STRING, tags ARRAY<STRING>, props MAP<STRING, STRING>)
+ROW FORMAT DELIMITED
+FIELDS TERMINATED BY ',' ESCAPED BY '\\'
+COLLECTION ITEMS TERMINATED BY '|'
+MAP KEYS TERMINATED BY ':'
+LINES TERMINATED BY '\n'
+NULL DEFINED AS 'NULL'
+STORED AS TEXTFILE;
+
+CREATE TABLE student_fields (id INT, name STRING)
+ROW FORMAT DELIMITED FIELDS TERMINATED BY '\t'
+STORED AS TEXTFILE;
+
+CREATE TABLE student_lines (id INT, name STRING)
+ROW FORMAT DELIMITED
+LINES TERMINATED BY '\n'
+NULL DEFINED AS ''
+STORED AS TEXTFILE
+LOCATION '/data/student_lines';
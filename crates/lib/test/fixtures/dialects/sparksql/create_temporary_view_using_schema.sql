@@ -0,0 +1,7 @@
+CREATE TEMPORARY VIEW v (a INT, b STRING)
+USING csv
+OPTIONS (path '/data/v.csv', header 'true');
+
+CREATE OR REPLACE TEMPORARY VIEW v (a INT COMMENT 'id', b DECIMAL(10, 2))
+USING csv
+OPTIONS (path '/data/v.csv');
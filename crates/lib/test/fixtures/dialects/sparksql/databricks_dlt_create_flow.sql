@@ -0,0 +1,18 @@
+CREATE FLOW append_orders
+AS INSERT INTO orders
+SELECT * FROM STREAM(live.orders_raw);
+
+CREATE FLOW backfill_orders
+COMMENT 'One off backfill of historical orders'
+AS INSERT INTO orders
+SELECT
+    order_id,
+    amount
+FROM live.orders_archive;
+
+CREATE FLOW customers_cdc
+AS APPLY CHANGES INTO live.customers
+FROM STREAM(live.customers_raw)
+KEYS (customer_id)
+SEQUENCE BY updated_at
+COLUMNS * EXCEPT (operation);
@@ -33,3 +33,9 @@ AS SELECT
     a,
     b
 FROM STREAM(live.customers_bronze);
+
+CREATE STREAMING TABLE customers_raw
+AS SELECT
+    a,
+    b
+FROM STREAM(live.customers_bronze);
@@ -118,3 +118,10 @@ WHEN NOT MATCHED AND s.deleted = FALSE THEN
         unique_key,
         new_value
     );
+
+-- merge with automatic schema evolution
+MERGE WITH SCHEMA EVOLUTION INTO target
+USING source
+    ON target.id = source.id
+WHEN MATCHED THEN UPDATE SET *
+WHEN NOT MATCHED THEN INSERT *;
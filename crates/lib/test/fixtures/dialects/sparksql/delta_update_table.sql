@@ -24,3 +24,6 @@ WHERE category NOT IN (
     FROM events2
     WHERE date > '2001-01-01'
 );
+
+-- update every row
+UPDATE events SET category = 'unknown';
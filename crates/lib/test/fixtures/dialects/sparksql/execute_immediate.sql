@@ -0,0 +1,9 @@
+EXECUTE IMMEDIATE 'SELECT 1';
+
+EXECUTE IMMEDIATE sql_string;
+
+EXECUTE IMMEDIATE 'SELECT SUM(c1) FROM VALUES (?), (?)' INTO total USING 5, 6;
+
+EXECUTE IMMEDIATE 'SELECT SUM(c1), MAX(c1) FROM t WHERE c2 = :val'
+INTO total, biggest
+USING 10 AS val;
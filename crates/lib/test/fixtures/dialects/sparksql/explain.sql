@@ -61,3 +61,7 @@ EXPLAIN INSERT INTO TABLE students VALUES
 ('Amy Smith', '123 Park Ave, San Jose', 111111);
 
 EXPLAIN DROP VIEW IF EXISTS view_identifier;
+
+EXPLAIN INSERT OVERWRITE TABLE students SELECT * FROM new_students;
+
+EXPLAIN EXTENDED CREATE TABLE student_copy AS SELECT * FROM student;
@@ -0,0 +1,7 @@
+SELECT a, count(*) FROM t GROUP BY ALL;
+
+SELECT a, b, sum(c) FROM t GROUP BY ALL ORDER BY ALL;
+
+SELECT a, b FROM t ORDER BY ALL DESC NULLS LAST;
+
+SELECT a, b FROM t ORDER BY ALL LIMIT 10;
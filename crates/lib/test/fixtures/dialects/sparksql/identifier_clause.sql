@@ -0,0 +1,15 @@
+SELECT * FROM IDENTIFIER('db.student');
+
+SELECT * FROM IDENTIFIER(:table_name) AS s WHERE s.id = 1;
+
+SELECT IDENTIFIER('id'), IDENTIFIER(:column_name) FROM student;
+
+SELECT s.name FROM student AS s JOIN IDENTIFIER('db.teacher') AS t ON s.id = t.id;
+
+CREATE TABLE IDENTIFIER('db.student_copy') (id INT, name STRING);
+
+CREATE TABLE IDENTIFIER(:table_name) AS SELECT * FROM student;
+
+INSERT INTO IDENTIFIER(:table_name) VALUES (1, 'Bob');
+
+DROP TABLE IDENTIFIER('db.student_copy');
@@ -0,0 +1,5 @@
+INSERT OVERWRITE TABLE t PARTITION (dt = '2020') IF NOT EXISTS
+SELECT a, b FROM src;
+
+INSERT OVERWRITE t PARTITION (dt = '2020', region = 'eu') IF NOT EXISTS
+SELECT a FROM src WHERE b > 1;
@@ -0,0 +1,6 @@
+SELECT .5e3, 1., 10L, 1.5BD, 3d, 0x1A;
+
+SELECT 1. AS one, 1.e2 AS hundred, 0XFF AS max_byte;
+
+SELECT 1.
+FROM t;
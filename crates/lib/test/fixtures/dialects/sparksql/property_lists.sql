@@ -0,0 +1,32 @@
+CREATE TABLE student (id INT, name STRING)
+USING CSV
+OPTIONS ('header' = 'true', 'sep' = ',')
+TBLPROPERTIES ('foo' = 'bar');
+
+CREATE TABLE student (id INT, name STRING)
+USING CSV
+OPTIONS ('header' 'true', 'sep' ',')
+TBLPROPERTIES ('foo' 'bar');
+
+CREATE TABLE student (id INT, name STRING)
+USING PARQUET
+TBLPROPERTIES (
+    'delta.appendOnly' = 'true',
+    'delta.logRetentionDuration' 'interval 30 days',
+    this.is.my.key = 12,
+    some_key true
+);
+
+CREATE DATABASE IF NOT EXISTS customer_db
+WITH DBPROPERTIES (
+    'Edited-by' 'John',
+    'Edit-date' = '01/01/2001'
+);
+
+ALTER DATABASE inventory SET DBPROPERTIES ('Edited-by' 'John', 'Edit-date' '01/01/2001');
+
+ALTER TABLE dbo.tab1 SET TBLPROPERTIES ('winner' 'loser');
+
+CREATE TABLE events
+USING JSON
+OPTIONS ('path' 's3://bucket/events/', 'multiline' 'true');
@@ -0,0 +1,20 @@
+SELECT * FROM (
+    SELECT a, b FROM t DISTRIBUTE BY a SORT BY b
+) AS s;
+
+SELECT * FROM (SELECT a FROM t CLUSTER BY a) AS s;
+
+SELECT * FROM (
+    SELECT a, b FROM t DISTRIBUTE BY a SORT BY b DESC LIMIT 10
+) AS s
+JOIN (SELECT a FROM u SORT BY a) AS v ON s.a = v.a;
+
+WITH cte AS (
+    SELECT a, b FROM t DISTRIBUTE BY a SORT BY b
+)
+SELECT * FROM cte;
+
+SELECT a FROM t WHERE a IN (SELECT a FROM u DISTRIBUTE BY a);
+
+INSERT INTO x
+SELECT a, b FROM t DISTRIBUTE BY a SORT BY b;
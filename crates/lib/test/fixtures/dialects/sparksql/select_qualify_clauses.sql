@@ -0,0 +1,71 @@
+SELECT a, b
+FROM t
+QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b DESC) = 1;
+
+SELECT a, b
+FROM t
+WHERE b > 0
+QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b DESC) = 1
+ORDER BY a
+LIMIT 10;
+
+SELECT a, SUM(b) AS total
+FROM t
+GROUP BY a
+QUALIFY RANK() OVER (ORDER BY SUM(b) DESC) <= 3;
+
+SELECT a, SUM(b) AS total
+FROM t
+WHERE b IS NOT NULL
+GROUP BY a
+HAVING SUM(b) > 10
+QUALIFY RANK() OVER (ORDER BY total DESC) <= 3
+ORDER BY total DESC
+LIMIT 3;
+
+SELECT a, b
+FROM t
+QUALIFY (ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1);
+
+SELECT a, b, ROW_NUMBER() OVER w AS rn
+FROM t
+WINDOW w AS (PARTITION BY a ORDER BY b)
+QUALIFY rn = 1;
+
+SELECT a, b
+FROM t
+QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1
+SORT BY a;
+
+SELECT a, b
+FROM t
+QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1
+DISTRIBUTE BY a
+SORT BY b;
+
+SELECT a, b
+FROM t
+QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1
+CLUSTER BY a;
+
+SELECT a
+FROM t
+QUALIFY a > 1;
+
+WITH deduped AS (
+    SELECT a, b
+    FROM t
+    QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1
+)
+SELECT * FROM deduped;
+
+SELECT *
+FROM (
+    SELECT a, b
+    FROM t
+    QUALIFY ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) = 1
+) AS sub;
+
+SELECT a FROM t1 QUALIFY ROW_NUMBER() OVER (ORDER BY a) = 1
+UNION ALL
+SELECT a FROM t2 QUALIFY ROW_NUMBER() OVER (ORDER BY a) = 1;
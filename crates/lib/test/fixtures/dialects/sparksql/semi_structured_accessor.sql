@@ -0,0 +1,16 @@
+SELECT data:['my key'].arr[2].x
+FROM t;
+
+SELECT data:a.b[0].c:d['e f'].g
+FROM t;
+
+SELECT data:['my key']['other key']
+FROM t;
+
+SELECT
+    data:store.fruit[0].weight::DOUBLE AS weight,
+    data:price*2 AS doubled,
+    data:a.b+1 AS incremented,
+    data:a-data:b AS difference
+FROM t
+WHERE data:['owner name'] = 'amy';
@@ -0,0 +1,13 @@
+SET spark.sql.shuffle.partitions = 200;
+
+SET spark.sql.shuffle.partitions=200;
+
+SET spark.sql.session.timeZone = 'America/Los_Angeles';
+
+SET spark.sql.legacy.timeParserPolicy = "LEGACY";
+
+SET spark.sql.adaptive.enabled = true;
+
+SET spark.sql.shuffle.partitions;
+
+SET -v;
@@ -0,0 +1,38 @@
+-- Input and output row formats around an explicit output schema
+SELECT TRANSFORM(zip_code, name, age)
+    ROW FORMAT DELIMITED
+    FIELDS TERMINATED BY '\t'
+    COLLECTION ITEMS TERMINATED BY ','
+    MAP KEYS TERMINATED BY ':'
+    LINES TERMINATED BY '\n'
+    NULL DEFINED AS '\\N'
+    USING 'python3 transform.py'
+    AS (zip_code string, name string, age int)
+    ROW FORMAT DELIMITED
+    FIELDS TERMINATED BY '\t'
+    ESCAPED BY '\\'
+    LINES TERMINATED BY '\n'
+    NULL DEFINED AS '\\N'
+FROM person;
+
+-- Input row format with schema-less output
+SELECT TRANSFORM(zip_code, name)
+    ROW FORMAT DELIMITED
+    FIELDS TERMINATED BY ','
+    USING 'python3 transform.py'
+FROM person;
+
+-- Output schema without brackets
+SELECT TRANSFORM(zip_code, name)
+    USING 'cat' AS zip_code, name
+FROM person;
+
+-- Qualified columns and expressions as inputs
+SELECT TRANSFORM(p.zip_code, upper(p.name), p.age + 1)
+    USING 'cat' AS (zip_code string, name string, age int)
+FROM person AS p;
+
+-- Set quantifier on the inputs
+SELECT TRANSFORM(DISTINCT zip_code, name)
+    USING 'cat' AS (zip_code, name)
+FROM person;
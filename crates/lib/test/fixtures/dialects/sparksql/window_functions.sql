@@ -124,3 +124,16 @@ SELECT
 FROM test_ignore_null AS ignore_nulls
     WINDOW w AS (ORDER BY ignore_nulls.id range between interval 6 days preceding and current row)
 ORDER BY ignore_nulls.id;
+
+SELECT
+    name,
+    salary,
+    SUM(salary) OVER (
+        ORDER BY salary ROWS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE TIES
+    ) AS windowed_sum,
+    AVG(salary) OVER (
+        PARTITION BY dept ORDER BY salary RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW EXCLUDE CURRENT ROW
+    ) AS others_avg,
+    COUNT(*) OVER (ORDER BY salary ROWS UNBOUNDED PRECEDING EXCLUDE GROUP) AS earlier,
+    MAX(salary) OVER (ORDER BY salary ROWS UNBOUNDED PRECEDING EXCLUDE NO OTHERS) AS running_max
+FROM employees;
@@ -0,0 +1,19 @@
+SELECT
+    first_value(x) IGNORE NULLS OVER (PARTITION BY a ORDER BY b) AS first_x,
+    last_value(x) RESPECT NULLS OVER (PARTITION BY a ORDER BY b) AS last_x
+FROM t;
+
+SELECT
+    lead(x, 1) IGNORE NULLS OVER w AS next_x,
+    lag(x, 1) IGNORE NULLS OVER w AS prev_x
+FROM t
+WINDOW w AS (PARTITION BY a ORDER BY b);
+
+SELECT first_value(x IGNORE NULLS) OVER (ORDER BY b) AS first_x
+FROM t;
+
+SELECT
+    a,
+    lead(x, 1) IGNORE NULLS OVER (PARTITION BY a ORDER BY b) AS next_x
+FROM t
+QUALIFY lead(x, 1) IGNORE NULLS OVER (PARTITION BY a ORDER BY b) IS NOT NULL;
@@ -0,0 +1,5 @@
+CREATE TABLE t AS SELECT a, b FROM src;
+
+CREATE TEMP TABLE IF NOT EXISTS main.t AS SELECT a, b FROM src WHERE a > 0;
+
+CREATE TABLE t AS WITH recent AS (SELECT a FROM src) SELECT a FROM recent;
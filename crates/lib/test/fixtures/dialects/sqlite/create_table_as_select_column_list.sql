@@ -0,0 +1,3 @@
+CREATE TABLE t (x, y) AS SELECT a, b FROM s;
+
+CREATE TABLE IF NOT EXISTS t (x, y) AS SELECT a, b FROM s WHERE a > 0;
@@ -0,0 +1,14 @@
+WITH cte AS (
+    SELECT a FROM t
+)
+SELECT a FROM cte;
+
+WITH cte AS MATERIALIZED (
+    SELECT a FROM t
+)
+SELECT a FROM cte;
+
+WITH cte (a) AS NOT MATERIALIZED (
+    SELECT a FROM t
+)
+SELECT a FROM cte;
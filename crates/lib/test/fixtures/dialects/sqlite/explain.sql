@@ -0,0 +1,11 @@
+EXPLAIN SELECT a FROM t;
+
+EXPLAIN QUERY PLAN SELECT a FROM t WHERE b = 1;
+
+EXPLAIN INSERT INTO t (a, b) VALUES (1, 2);
+
+EXPLAIN QUERY PLAN UPDATE t SET a = 1 WHERE b = 2;
+
+EXPLAIN CREATE TABLE t (a INTEGER PRIMARY KEY, b TEXT);
+
+EXPLAIN DROP TABLE t;
@@ -0,0 +1,11 @@
+SELECT a FROM t LIMIT my_func();
+
+SELECT a FROM t LIMIT 10 OFFSET :n;
+
+SELECT a FROM t LIMIT ? OFFSET ?2;
+
+SELECT a FROM t LIMIT @lim OFFSET $off;
+
+SELECT a FROM t LIMIT 5, my_func();
+
+SELECT a FROM t WHERE b = :b;
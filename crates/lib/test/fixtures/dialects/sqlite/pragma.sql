@@ -27,3 +27,11 @@ PRAGMA schema.synchronous = 0;
 PRAGMA temp_store = DEFAULT;
 
 PRAGMA schema.wal_checkpoint(FULL);
+
+PRAGMA table_info(users);
+
+PRAGMA foreign_key_list(t);
+
+PRAGMA schema.index_list("users");
+
+PRAGMA table_xinfo('users');
@@ -0,0 +1,27 @@
+SELECT o.id
+FROM orders AS o
+WHERE EXISTS (
+    SELECT 1
+    FROM order_items AS i
+    WHERE i.order_id = o.id
+);
+
+SELECT c.id
+FROM customers AS c
+WHERE NOT EXISTS (
+    SELECT 1
+    FROM orders AS o
+    WHERE o.customer_id = c.id
+        AND o.status = 'open'
+);
+
+SELECT c.id
+FROM customers AS c
+WHERE c.active = 1
+    AND EXISTS (SELECT 1 FROM orders AS o WHERE o.customer_id = c.id)
+    OR NOT EXISTS (SELECT * FROM refunds AS r WHERE r.customer_id = c.id);
+
+SELECT
+    c.id,
+    EXISTS (SELECT 1 FROM orders AS o WHERE o.customer_id = c.id) AS has_orders
+FROM customers AS c;
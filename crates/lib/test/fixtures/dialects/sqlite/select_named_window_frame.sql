@@ -0,0 +1,12 @@
+SELECT
+    a,
+    SUM(b) OVER w AS sum_b,
+    AVG(b) OVER (w) AS avg_b,
+    MAX(b) OVER (w ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS max_b,
+    MIN(b) OVER (w GROUPS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS min_b
+FROM t
+WINDOW w AS (PARTITION BY a ORDER BY c);
+
+SELECT
+    COUNT(*) FILTER (WHERE b > 1) OVER (PARTITION BY a) AS c
+FROM t;
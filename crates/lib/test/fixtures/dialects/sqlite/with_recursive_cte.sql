@@ -0,0 +1,15 @@
+WITH RECURSIVE numbers (n) AS (
+    SELECT 1
+    UNION ALL
+    SELECT n + 1 FROM numbers WHERE n < 10
+)
+SELECT n FROM numbers;
+
+WITH RECURSIVE ancestors AS (
+    SELECT id, parent_id FROM nodes WHERE id = 42
+    UNION ALL
+    SELECT nodes.id, nodes.parent_id
+    FROM nodes
+    INNER JOIN ancestors ON nodes.id = ancestors.parent_id
+)
+SELECT id FROM ancestors;
@@ -16,8 +16,8 @@ pub struct Args {
 }
 
 impl Args {
-    fn parse_args(&mut self, mut iter: impl Iterator<Item = String>) {
-        while let Some(arg) = iter.next() {
+    fn parse_args(&mut self, iter: impl Iterator<Item = String>) {
+        for arg in iter {
             if arg == "--" {
                 continue;
             }
@@ -140,7 +140,7 @@ fn main() {
             let has_config = !case.configs.is_empty();
 
             if has_config {
-                *linter.config_mut() = FluffConfig::new(case.configs.clone(), None, None);
+                *linter.config_mut() = FluffConfig::new(case.configs.clone(), None, None).unwrap();
                 linter.config_mut().raw.extend(core.clone());
 
                 if let Some(core) = case.configs.get("core").and_then(|it| it.as_map()) {
@@ -2,29 +2,31 @@ use ahash::AHashMap;
 use lsp_server::{Connection, Message, Request, RequestId, Response};
 use lsp_types::notification::{
     DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
-    Notification, PublishDiagnostics,
+    Notification, PublishDiagnostics, ShowMessage,
 };
 use lsp_types::request::{Formatting, Request as _};
 use lsp_types::{
     Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
     DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentFormattingParams,
-    InitializeParams, InitializeResult, NumberOrString, OneOf, Position, PublishDiagnosticsParams,
-    Registration, ServerCapabilities, TextDocumentIdentifier, TextDocumentItem,
-    TextDocumentSyncCapability, TextDocumentSyncKind, Uri, VersionedTextDocumentIdentifier,
+    InitializeParams, InitializeResult, MessageType, NumberOrString, OneOf, Position,
+    PublishDiagnosticsParams, Registration, ServerCapabilities, ShowMessageParams,
+    TextDocumentIdentifier, TextDocumentItem, TextDocumentSyncCapability, TextDocumentSyncKind,
+    Uri, VersionedTextDocumentIdentifier,
 };
 use serde_json::Value;
 use sqruff_lib::core::config::FluffConfig;
+use sqruff_lib::core::errors::SQLFluffUserError;
 use sqruff_lib::core::linter::linter::Linter;
 use wasm_bindgen::prelude::*;
 
 #[cfg(not(target_arch = "wasm32"))]
-fn load_config() -> FluffConfig {
-    FluffConfig::from_root(None, false, None).unwrap_or_default()
+fn load_config() -> Result<FluffConfig, SQLFluffUserError> {
+    FluffConfig::from_root(None, false, None)
 }
 
 #[cfg(target_arch = "wasm32")]
-fn load_config() -> FluffConfig {
-    FluffConfig::default()
+fn load_config() -> Result<FluffConfig, SQLFluffUserError> {
+    Ok(FluffConfig::default())
 }
 
 fn server_initialize_result() -> InitializeResult {
@@ -41,6 +43,7 @@ fn server_initialize_result() -> InitializeResult {
 pub struct LanguageServer {
     linter: Linter,
     send_diagnostics_callback: Box<dyn Fn(PublishDiagnosticsParams)>,
+    show_message_callback: Box<dyn Fn(ShowMessageParams)>,
     documents: AHashMap<Uri, String>,
 }
 
@@ -50,15 +53,25 @@ pub struct Wasm(LanguageServer);
 #[wasm_bindgen]
 impl Wasm {
     #[wasm_bindgen(constructor)]
-    pub fn new(send_diagnostics_callback: js_sys::Function) -> Self {
+    pub fn new(
+        send_diagnostics_callback: js_sys::Function,
+        show_message_callback: js_sys::Function,
+    ) -> Self {
         console_error_panic_hook::set_once();
 
         let send_diagnostics_callback = Box::leak(Box::new(send_diagnostics_callback));
-
-        Self(LanguageServer::new(|diagnostics| {
-            let diagnostics = serde_wasm_bindgen::to_value(&diagnostics).unwrap();
-            send_diagnostics_callback.call1(&JsValue::null(), &diagnostics).unwrap();
-        }))
+        let show_message_callback = Box::leak(Box::new(show_message_callback));
+
+        Self(LanguageServer::new(
+            |diagnostics| {
+                let diagnostics = serde_wasm_bindgen::to_value(&diagnostics).unwrap();
+                send_diagnostics_callback.call1(&JsValue::null(), &diagnostics).unwrap();
+            },
+            |message| {
+                let message = serde_wasm_bindgen::to_value(&message).unwrap();
+                show_message_callback.call1(&JsValue::null(), &message).unwrap();
+            },
+        ))
     }
 
     #[wasm_bindgen(js_name = saveRegistrationOptions)]
@@ -68,7 +81,7 @@ impl Wasm {
 
     #[wasm_bindgen(js_name = updateConfig)]
     pub fn update_config(&mut self, source: &str) {
-        *self.0.linter.config_mut() = FluffConfig::from_source(source);
+        self.0.set_config(FluffConfig::from_source(source));
         self.0.recheck_files();
     }
 
@@ -91,12 +104,30 @@ impl Wasm {
 }
 
 impl LanguageServer {
-    pub fn new(send_diagnostics_callback: impl Fn(PublishDiagnosticsParams) + 'static) -> Self {
-        Self {
-            linter: Linter::new(load_config(), None, None),
+    pub fn new(
+        send_diagnostics_callback: impl Fn(PublishDiagnosticsParams) + 'static,
+        show_message_callback: impl Fn(ShowMessageParams) + 'static,
+    ) -> Self {
+        let mut this = Self {
+            linter: Linter::new(FluffConfig::default(), None, None),
             send_diagnostics_callback: Box::new(send_diagnostics_callback),
+            show_message_callback: Box::new(show_message_callback),
             documents: AHashMap::new(),
-        }
+        };
+        this.set_config(load_config());
+        this
+    }
+
+    /// Uses `config`, or falls back to the default config and shows the error
+    /// to the user if it could not be loaded.
+    fn set_config(&mut self, config: Result<FluffConfig, SQLFluffUserError>) {
+        *self.linter.config_mut() = config.unwrap_or_else(|error| {
+            (self.show_message_callback)(ShowMessageParams {
+                typ: MessageType::ERROR,
+                message: format!("sqruff: failed to load config, using the defaults: {error}"),
+            });
+            FluffConfig::default()
+        });
     }
 
     fn on_request(&mut self, id: RequestId, method: &str, params: Value) -> Option<Response> {
@@ -160,7 +191,7 @@ impl LanguageServer {
                 let uri = params.text_document.uri.as_str();
 
                 if uri.ends_with(".sqlfluff") || uri.ends_with(".sqruff") {
-                    *self.linter.config_mut() = load_config();
+                    self.set_config(load_config());
 
                     self.recheck_files();
                 }
@@ -225,11 +256,18 @@ pub fn run() {
 }
 
 fn main_loop(connection: Connection, _init_param: InitializeParams) {
-    let sender = connection.sender.clone();
-    let mut lsp = LanguageServer::new(move |diagnostics| {
-        let notification = new_notification::<PublishDiagnostics>(diagnostics);
-        sender.send(Message::Notification(notification)).unwrap();
-    });
+    let diagnostics_sender = connection.sender.clone();
+    let message_sender = connection.sender.clone();
+    let mut lsp = LanguageServer::new(
+        move |diagnostics| {
+            let notification = new_notification::<PublishDiagnostics>(diagnostics);
+            diagnostics_sender.send(Message::Notification(notification)).unwrap();
+        },
+        move |message| {
+            let notification = new_notification::<ShowMessage>(message);
+            message_sender.send(Message::Notification(notification)).unwrap();
+        },
+    );
 
     let params = save_registration_options();
     connection
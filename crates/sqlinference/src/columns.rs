@@ -61,7 +61,7 @@ mod tests {
 
     #[test]
     fn test_get_columns_internal() {
-        let config = FluffConfig::new(Default::default(), None, None);
+        let config = FluffConfig::new(Default::default(), None, None).unwrap();
         let parser = Parser::new(&config, None);
 
         let (cols, unnamed) = get_columns_internal(
@@ -2444,6 +2444,7 @@ LEFT JOIN q.shift_last sl
     }
 
     #[test]
+    #[allow(clippy::type_complexity)]
     fn test_get_column_with_source() {
         let tests: Vec<(&str, Vec<(&str, (&str, &str))>, Vec<&str>, Vec<&str>)> = vec![
             ("SELECT a FROM q.model_a", vec![("a", ("q.model_a", "a"))], vec![], vec![]),
@@ -6,6 +6,8 @@ import {
   BrowserMessageReader,
   BrowserMessageWriter,
   PublishDiagnosticsParams,
+  ShowMessageNotification,
+  ShowMessageParams,
   RequestType,
   DocumentFormattingParams,
 } from "vscode-languageserver/browser";
@@ -33,7 +35,10 @@ sqruffInit(sqruffWasmData).then(() => {
   const sendDiagnosticsCallback = (params: PublishDiagnosticsParams) =>
     connection.sendDiagnostics(params);
 
-  let lsp = new sqruffLsp.Wasm(sendDiagnosticsCallback);
+  const showMessageCallback = (params: ShowMessageParams) =>
+    connection.sendNotification(ShowMessageNotification.type, params);
+
+  let lsp = new sqruffLsp.Wasm(sendDiagnosticsCallback, showMessageCallback);
 
   connection.onInitialize(() => lsp.onInitialize());
   connection.onInitialized(() => updateConfig());